@@ -0,0 +1,76 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Compares formatting of decimal numbers into strings and byte buffers.
+//!
+//! Runs on stable Rust, prints results in the same format as the built-in
+//! benchmark harness, e.g. `test bench_write_to ... bench: 25 ns/iter`.
+
+use dfp_number::Decimal128;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Number of iterations of every benchmark.
+const ITERATIONS: u32 = 1_000_000;
+
+/// Runs `f` for all inputs and prints the average time of a single call.
+fn bench(name: &str, input: &[Decimal128], mut f: impl FnMut(Decimal128)) {
+  let start = Instant::now();
+  for _ in 0..ITERATIONS {
+    for x in input {
+      f(black_box(*x));
+    }
+  }
+  let nanos =
+    start.elapsed().as_nanos() / (ITERATIONS as u128 * input.len() as u128);
+  println!("test {:<30} ... bench: {:>10} ns/iter", name, nanos);
+}
+
+fn main() {
+  let input = [
+    "0",
+    "12.50",
+    "-1234.56",
+    "100000",
+    "3.1415926535897932384626433832795",
+    "-0.000123",
+  ]
+  .map(Decimal128::from);
+  let mut s = String::with_capacity(64);
+  bench("bench_display", &input, |x| {
+    s.clear();
+    write!(s, "{}", x).unwrap();
+    black_box(&s);
+  });
+  bench("bench_display_precision", &input, |x| {
+    s.clear();
+    write!(s, "{:.2}", x).unwrap();
+    black_box(&s);
+  });
+  let mut buf = [0; 64];
+  bench("bench_write_to", &input, |x| {
+    black_box(x.write_to(&mut buf));
+  });
+}
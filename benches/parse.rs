@@ -0,0 +1,68 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Compares parsing of decimal numbers from strings and bytes.
+//!
+//! Runs on stable Rust, prints results in the same format as the built-in
+//! benchmark harness, e.g. `test bench_parse_bytes ... bench: 25 ns/iter`.
+
+use dfp_number::Decimal128;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Number of iterations of every benchmark.
+const ITERATIONS: u32 = 1_000_000;
+
+/// Typical values found in CSV and fixed-width files.
+const INPUT: [&str; 8] = [
+  "0",
+  "12.50",
+  "-1234.56",
+  "100000",
+  "3.1415926535897932384626433832795",
+  "-0.000123",
+  "98765432109876543210",
+  "1.5E+3",
+];
+
+/// Runs `f` for all inputs and prints the average time of a single call.
+fn bench(name: &str, f: impl Fn(&str) -> Decimal128) {
+  let start = Instant::now();
+  for _ in 0..ITERATIONS {
+    for s in INPUT {
+      black_box(f(black_box(s)));
+    }
+  }
+  let nanos =
+    start.elapsed().as_nanos() / (ITERATIONS as u128 * INPUT.len() as u128);
+  println!("test {:<30} ... bench: {:>10} ns/iter", name, nanos);
+}
+
+fn main() {
+  bench("bench_from_str_lossy", Decimal128::from_str_lossy);
+  bench("bench_from_str", |s| s.parse().unwrap());
+  bench("bench_parse_bytes", |s| {
+    Decimal128::parse_bytes(s.as_bytes()).unwrap()
+  });
+}
@@ -0,0 +1,173 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Accounting-style formatting of decimal numbers.
+
+use crate::{Decimal128, NumberFormat, SignPosition};
+
+/// Position of the currency symbol relative to the number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum CurrencyPosition {
+  /// The currency symbol precedes the number, like in `$1,234.00`.
+  #[default]
+  Before,
+  /// The currency symbol follows the number, like in `1.234,00 €`.
+  After,
+}
+
+/// Style of writing negative numbers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum NegativeStyle {
+  /// The minus sign is written at the sign position of the number format,
+  /// like in `-$1,234.00` or `$1,234.00-`.
+  #[default]
+  Sign,
+  /// The number together with the currency symbol is enclosed in parentheses,
+  /// like in `($1,234.00)`.
+  Parentheses,
+  /// Negative numbers are followed by `CR` (credit) and positive numbers
+  /// by `DR` (debit), like in `$1,234.00 CR`, zero has no suffix.
+  CreditDebit,
+}
+
+/// Formatter of amounts in financial reports.
+///
+/// Digits are written according to a [NumberFormat], the amount is then
+/// decorated with the currency symbol and negative amounts are marked
+/// according to the [NegativeStyle]. Amounts that are zero after rounding
+/// to the number of fraction digits are never marked as negative.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{AccountingFormat, Decimal128, NegativeStyle, NumberFormat};
+///
+/// let number = NumberFormat::new('.')
+///   .with_grouping_separator(',')
+///   .with_min_fraction_digits(2)
+///   .with_max_fraction_digits(2);
+/// let accounting = AccountingFormat::new(number)
+///   .with_currency("$")
+///   .with_negative_style(NegativeStyle::Parentheses);
+/// assert_eq!("$1,234.00", accounting.format(Decimal128::from(1234)));
+/// assert_eq!("($1,234.00)", accounting.format(Decimal128::from(-1234)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct AccountingFormat {
+  /// Format of digits of the amount.
+  number_format: NumberFormat,
+  /// Currency symbol.
+  currency: Option<String>,
+  /// Position of the currency symbol.
+  currency_position: CurrencyPosition,
+  /// Flag indicating if the currency symbol is separated from the number by a space.
+  currency_spacing: bool,
+  /// Style of writing negative amounts.
+  negative_style: NegativeStyle,
+}
+
+impl AccountingFormat {
+  /// Creates an accounting format writing digits according to given number format,
+  /// without currency symbol and with negative amounts marked by the sign.
+  pub fn new(number_format: NumberFormat) -> Self {
+    Self {
+      number_format,
+      ..Default::default()
+    }
+  }
+  /// Returns this format with given currency symbol.
+  pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+    self.currency = Some(currency.into());
+    self
+  }
+  /// Returns this format with given position of the currency symbol.
+  pub fn with_currency_position(mut self, position: CurrencyPosition) -> Self {
+    self.currency_position = position;
+    self
+  }
+  /// Returns this format with the currency symbol separated
+  /// from the number by a space, like in `1.234,00 €`.
+  pub fn with_currency_spacing(mut self, spacing: bool) -> Self {
+    self.currency_spacing = spacing;
+    self
+  }
+  /// Returns this format with given style of negative amounts.
+  pub fn with_negative_style(mut self, style: NegativeStyle) -> Self {
+    self.negative_style = style;
+    self
+  }
+  /// Returns the format of digits.
+  pub fn number_format(&self) -> &NumberFormat {
+    &self.number_format
+  }
+  /// Returns the currency symbol.
+  pub fn currency(&self) -> Option<&str> {
+    self.currency.as_deref()
+  }
+  /// Returns the position of the currency symbol.
+  pub fn currency_position(&self) -> CurrencyPosition {
+    self.currency_position
+  }
+  /// Returns `true` when the currency symbol is separated from the number by a space.
+  pub fn currency_spacing(&self) -> bool {
+    self.currency_spacing
+  }
+  /// Returns the style of negative amounts.
+  pub fn negative_style(&self) -> NegativeStyle {
+    self.negative_style
+  }
+  /// Formats an amount in this format.
+  ///
+  /// Infinities and NaNs are written like by [Display](std::fmt::Display).
+  pub fn format(&self, x: Decimal128) -> String {
+    let Some((negative, digits)) = self.number_format.format_unsigned(x) else {
+      return x.to_string();
+    };
+    let zero = !digits.bytes().any(|ch| (b'1'..=b'9').contains(&ch));
+    let negative = negative && !zero;
+    let space = if self.currency_spacing { " " } else { "" };
+    let amount = match (self.currency.as_deref(), self.currency_position) {
+      (Some(currency), CurrencyPosition::Before) => {
+        format!("{}{}{}", currency, space, digits)
+      }
+      (Some(currency), CurrencyPosition::After) => {
+        format!("{}{}{}", digits, space, currency)
+      }
+      (None, _) => digits,
+    };
+    match self.negative_style {
+      NegativeStyle::Sign if negative => {
+        if self.number_format.sign_position() == SignPosition::Trailing {
+          format!("{}-", amount)
+        } else {
+          format!("-{}", amount)
+        }
+      }
+      NegativeStyle::Parentheses if negative => format!("({})", amount),
+      NegativeStyle::CreditDebit if negative => format!("{} CR", amount),
+      NegativeStyle::CreditDebit if !zero => format!("{} DR", amount),
+      _ => amount,
+    }
+  }
+}
@@ -0,0 +1,246 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Bindings for 128-bit decimal floating-point functions
+//! of the **Intel(R) Decimal Floating-Point Math Library**
+//! that are not exposed by the `dfp-number-sys` crate.
+
+use crate::bid32::BID32;
+use crate::bid64::BID64;
+use dfp_number_sys::BID128;
+use std::ffi::{c_int, c_uint};
+
+#[rustfmt::skip]
+extern "C" {
+  fn __bid128_cbrt(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_exp10(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_exp2(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_expm1(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_hypot(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_isInf(x: BID128) -> c_int;
+  fn __bid128_isNaN(x: BID128) -> c_int;
+  fn __bid128_log10(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_log1p(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_log2(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_nextdown(x: BID128, flags: *mut c_uint) -> BID128;
+  fn __bid128_nextup(x: BID128, flags: *mut c_uint) -> BID128;
+  fn __bid128_to_bid32(x: BID128, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid128_to_bid64(x: BID128, round: c_uint, flags: *mut c_uint) -> BID64;
+}
+
+/// Returns the cube root of `x`.
+pub fn bid128_cbrt(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_cbrt(x, round, flags) }
+}
+
+/// Returns 10 raised to the power of `x`.
+pub fn bid128_exp10(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_exp10(x, round, flags) }
+}
+
+/// Returns 2 raised to the power of `x`.
+pub fn bid128_exp2(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_exp2(x, round, flags) }
+}
+
+/// Returns `e` raised to the power of `x`, minus one.
+pub fn bid128_expm1(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_expm1(x, round, flags) }
+}
+
+/// Returns the square root of `x * x + y * y`, without undue overflow or underflow.
+pub fn bid128_hypot(
+  x: BID128,
+  y: BID128,
+  round: u32,
+  flags: &mut u32,
+) -> BID128 {
+  unsafe { __bid128_hypot(x, y, round, flags) }
+}
+
+/// Returns `true` if and only if `x` is infinite.
+pub fn bid128_is_inf(x: BID128) -> bool {
+  unsafe { __bid128_isInf(x) != 0 }
+}
+
+/// Returns `true` if and only if `x` is a NaN.
+pub fn bid128_is_nan(x: BID128) -> bool {
+  unsafe { __bid128_isNaN(x) != 0 }
+}
+
+/// Returns the base 10 logarithm of `x`.
+pub fn bid128_log10(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_log10(x, round, flags) }
+}
+
+/// Returns the natural logarithm of `1 + x`.
+pub fn bid128_log1p(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_log1p(x, round, flags) }
+}
+
+/// Returns the base 2 logarithm of `x`.
+pub fn bid128_log2(x: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_log2(x, round, flags) }
+}
+
+/// Returns the largest value less than `x`.
+pub fn bid128_nextdown(x: BID128, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_nextdown(x, flags) }
+}
+
+/// Returns the smallest value greater than `x`.
+pub fn bid128_nextup(x: BID128, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_nextup(x, flags) }
+}
+
+/// Converts 128-bit decimal floating-point value to 32-bit decimal floating-point format.
+pub fn bid128_to_bid32(x: BID128, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid128_to_bid32(x, round, flags) }
+}
+
+/// Converts 128-bit decimal floating-point value to 64-bit decimal floating-point format.
+pub fn bid128_to_bid64(x: BID128, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid128_to_bid64(x, round, flags) }
+}
+
+/// Index of the word holding the most significant 64 bits.
+const HIGH: usize = if cfg!(target_endian = "big") { 0 } else { 1 };
+/// Index of the word holding the least significant 64 bits.
+const LOW: usize = 1 - HIGH;
+/// Bit holding the sign.
+const SIGN: u64 = 1 << 63;
+/// Bits marking an infinity.
+const INF: u64 = 0x7800_0000_0000_0000;
+/// Bits marking a quiet NaN.
+const QNAN: u64 = 0x7C00_0000_0000_0000;
+/// Bits marking a signaling NaN.
+const SNAN: u64 = 0x7E00_0000_0000_0000;
+/// Bias added to the exponent in the encoding.
+pub const BID128_EXPONENT_BIAS: i32 = 6176;
+/// Minimum exponent of the coefficient.
+pub const BID128_MIN_EXPONENT: i32 = -BID128_EXPONENT_BIAS;
+/// Maximum exponent of the coefficient.
+pub const BID128_MAX_EXPONENT: i32 = 6111;
+
+/// Returns 128-bit words with given high and low 64 bits.
+fn words(high: u64, low: u64) -> BID128 {
+  let mut w = [0; 2];
+  w[HIGH] = high;
+  w[LOW] = low;
+  BID128 { w }
+}
+
+/// Encodes a finite number `(-1)^sign * coefficient * 10^exponent`.
+///
+/// The coefficient must be less than `10^34` and the exponent must be
+/// in range from [BID128_MIN_EXPONENT] to [BID128_MAX_EXPONENT].
+pub fn bid128_from_parts(
+  negative: bool,
+  coefficient: u128,
+  exponent: i32,
+) -> BID128 {
+  let biased = (exponent + BID128_EXPONENT_BIAS) as u64;
+  let sign = if negative { SIGN } else { 0 };
+  words(
+    sign | biased << 49 | (coefficient >> 64) as u64,
+    coefficient as u64,
+  )
+}
+
+/// Encodes an infinity.
+pub fn bid128_inf(negative: bool) -> BID128 {
+  words(if negative { SIGN | INF } else { INF }, 0)
+}
+
+/// Encodes a quiet or signaling NaN with given payload,
+/// the payload must be less than `10^33`.
+pub fn bid128_nan(negative: bool, signaling: bool, payload: u128) -> BID128 {
+  let sign = if negative { SIGN } else { 0 };
+  let nan = if signaling { SNAN } else { QNAN };
+  words(sign | nan | (payload >> 64) as u64, payload as u64)
+}
+
+/// Decoded 128-bit decimal floating point number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Unpacked {
+  /// Finite number `(-1)^negative * coefficient * 10^exponent`.
+  Finite {
+    negative: bool,
+    coefficient: u128,
+    exponent: i32,
+  },
+  /// Positive or negative infinity.
+  Infinite { negative: bool },
+  /// Quiet or signaling NaN with diagnostic payload.
+  NaN {
+    negative: bool,
+    signaling: bool,
+    payload: u128,
+  },
+}
+
+/// Decodes a number into sign, coefficient and exponent.
+///
+/// Non-canonical coefficients and payloads are decoded as zero.
+pub fn bid128_unpack(x: BID128) -> Unpacked {
+  let (high, low) = (x.w[HIGH], x.w[LOW]);
+  let negative = high & SIGN != 0;
+  if high & QNAN == QNAN {
+    let payload = ((high & 0x0000_3FFF_FFFF_FFFF) as u128) << 64 | low as u128;
+    let payload = if payload < 10_u128.pow(33) {
+      payload
+    } else {
+      0
+    };
+    return Unpacked::NaN {
+      negative,
+      signaling: high & SNAN == SNAN,
+      payload,
+    };
+  }
+  if high & INF == INF {
+    return Unpacked::Infinite { negative };
+  }
+  let (biased, coefficient) =
+    if high & 0x6000_0000_0000_0000 == 0x6000_0000_0000_0000 {
+      // coefficients in this form are always greater than the maximum
+      ((high >> 47) & 0x3FFF, 0)
+    } else {
+      let coefficient =
+        ((high & 0x0001_FFFF_FFFF_FFFF) as u128) << 64 | low as u128;
+      (
+        (high >> 49) & 0x3FFF,
+        if coefficient < 10_u128.pow(34) {
+          coefficient
+        } else {
+          0
+        },
+      )
+    };
+  Unpacked::Finite {
+    negative,
+    coefficient,
+    exponent: biased as i32 - BID128_EXPONENT_BIAS,
+  }
+}
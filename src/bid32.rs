@@ -0,0 +1,238 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Bindings for 32-bit decimal floating-point functions
+//! of the **Intel(R) Decimal Floating-Point Math Library**.
+//!
+//! The library is compiled and linked by the `dfp-number-sys` crate,
+//! only the 128-bit functions are exposed there, so the 32-bit
+//! counterparts are declared here.
+
+use crate::bid64::BID64;
+use dfp_number_sys::BID128;
+use std::ffi::{c_char, c_int, c_uint, CStr, CString};
+
+/// 32-bit decimal in binary integer decimal (BID) encoding.
+pub type BID32 = u32;
+
+#[rustfmt::skip]
+extern "C" {
+  fn __bid32_add(x: BID32, y: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_div(x: BID32, y: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_exp(x: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_from_int32(x: c_int, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_from_int64(x: i64, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_from_string(s: *const c_char, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_from_uint32(x: c_uint, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_from_uint64(x: u64, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_isFinite(x: BID32) -> c_int;
+  fn __bid32_isInf(x: BID32) -> c_int;
+  fn __bid32_isNaN(x: BID32) -> c_int;
+  fn __bid32_isSigned(x: BID32) -> c_int;
+  fn __bid32_isZero(x: BID32) -> c_int;
+  fn __bid32_log(x: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_maxnum(x: BID32, y: BID32, flags: *mut c_uint) -> BID32;
+  fn __bid32_minnum(x: BID32, y: BID32, flags: *mut c_uint) -> BID32;
+  fn __bid32_mul(x: BID32, y: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_negate(x: BID32) -> BID32;
+  fn __bid32_quantize(x: BID32, y: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_quiet_equal(x: BID32, y: BID32, flags: *mut c_uint) -> c_int;
+  fn __bid32_quiet_greater(x: BID32, y: BID32, flags: *mut c_uint) -> c_int;
+  fn __bid32_quiet_greater_equal(x: BID32, y: BID32, flags: *mut c_uint) -> c_int;
+  fn __bid32_quiet_less(x: BID32, y: BID32, flags: *mut c_uint) -> c_int;
+  fn __bid32_quiet_less_equal(x: BID32, y: BID32, flags: *mut c_uint) -> c_int;
+  fn __bid32_scalbn(x: BID32, n: c_int, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_sub(x: BID32, y: BID32, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid32_to_bid128(x: BID32, flags: *mut c_uint) -> BID128;
+  fn __bid32_to_bid64(x: BID32, flags: *mut c_uint) -> BID64;
+  fn __bid32_to_string(s: *mut c_char, x: BID32, flags: *mut c_uint);
+}
+
+/// Returns a result of decimal floating-point addition.
+pub fn bid32_add(x: BID32, y: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_add(x, y, round, flags) }
+}
+
+/// Returns a result of decimal floating-point division.
+pub fn bid32_div(x: BID32, y: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_div(x, y, round, flags) }
+}
+
+/// Returns the value of `e` raised to the `x`th power.
+pub fn bid32_exp(x: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_exp(x, round, flags) }
+}
+
+/// Converts 32-bit signed integer to 32-bit decimal floating-point number,
+/// rounding when the integer has more than 7 digits.
+pub fn bid32_from_int32(x: i32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_from_int32(x, round, flags) }
+}
+
+/// Converts 64-bit signed integer to 32-bit decimal floating-point number,
+/// rounding when the integer has more than 7 digits.
+pub fn bid32_from_int64(x: i64, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_from_int64(x, round, flags) }
+}
+
+/// Converts a decimal floating-point value represented in string format
+/// to 32-bit decimal floating-point format.
+pub fn bid32_from_string(s: &str, round: u32, flags: &mut u32) -> BID32 {
+  // input with an interior NUL is invalid, the library converts empty string to NaN
+  let c_s = CString::new(s).unwrap_or_default();
+  unsafe { __bid32_from_string(c_s.as_ptr(), round, flags) }
+}
+
+/// Converts 32-bit unsigned integer to 32-bit decimal floating-point number,
+/// rounding when the integer has more than 7 digits.
+pub fn bid32_from_uint32(x: u32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_from_uint32(x, round, flags) }
+}
+
+/// Converts 64-bit unsigned integer to 32-bit decimal floating-point number,
+/// rounding when the integer has more than 7 digits.
+pub fn bid32_from_uint64(x: u64, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_from_uint64(x, round, flags) }
+}
+
+/// Returns `true` if and only if `x` is zero, subnormal or normal (not infinite or NaN).
+pub fn bid32_is_finite(x: BID32) -> bool {
+  unsafe { __bid32_isFinite(x) != 0 }
+}
+
+/// Returns `true` if and only if `x` is infinite.
+pub fn bid32_is_inf(x: BID32) -> bool {
+  unsafe { __bid32_isInf(x) != 0 }
+}
+
+/// Returns `true` if and only if `x` is a NaN.
+pub fn bid32_is_nan(x: BID32) -> bool {
+  unsafe { __bid32_isNaN(x) != 0 }
+}
+
+/// Returns `true` if and only if `x` has negative sign.
+pub fn bid32_is_signed(x: BID32) -> bool {
+  unsafe { __bid32_isSigned(x) != 0 }
+}
+
+/// Returns `true` if and only if `x` is `+0` or `-0`.
+pub fn bid32_is_zero(x: BID32) -> bool {
+  unsafe { __bid32_isZero(x) != 0 }
+}
+
+/// Returns natural logarithm of `x`.
+pub fn bid32_log(x: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_log(x, round, flags) }
+}
+
+/// Returns the canonicalized floating-point number `y` if `x < y`,
+/// `x` if `y < x`, the canonicalized floating-point number if one operand
+/// is a floating-point number and the other a quiet NaN.
+pub fn bid32_maxnum(x: BID32, y: BID32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_maxnum(x, y, flags) }
+}
+
+/// Returns the canonicalized floating-point number `x` if `x < y`,
+/// `y` if `y < x`, the canonicalized floating-point number if one operand
+/// is a floating-point number and the other a quiet NaN.
+pub fn bid32_minnum(x: BID32, y: BID32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_minnum(x, y, flags) }
+}
+
+/// Returns a result of decimal floating-point multiplication.
+pub fn bid32_mul(x: BID32, y: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_mul(x, y, round, flags) }
+}
+
+/// Returns the same value as `x` but with reversed sign.
+pub fn bid32_negate(x: BID32) -> BID32 {
+  unsafe { __bid32_negate(x) }
+}
+
+/// Returns a number that is equal in value (except for any rounding)
+/// to `x` and which has an exponent equal to the exponent of `y`.
+pub fn bid32_quantize(
+  x: BID32,
+  y: BID32,
+  round: u32,
+  flags: &mut u32,
+) -> BID32 {
+  unsafe { __bid32_quantize(x, y, round, flags) }
+}
+
+/// Returns `true` if `x == y`, does not signal invalid exception for quiet NaNs.
+pub fn bid32_quiet_equal(x: BID32, y: BID32, flags: &mut u32) -> bool {
+  unsafe { __bid32_quiet_equal(x, y, flags) != 0 }
+}
+
+/// Returns `true` if `x > y`, does not signal invalid exception for quiet NaNs.
+pub fn bid32_quiet_greater(x: BID32, y: BID32, flags: &mut u32) -> bool {
+  unsafe { __bid32_quiet_greater(x, y, flags) != 0 }
+}
+
+/// Returns `true` if `x >= y`, does not signal invalid exception for quiet NaNs.
+pub fn bid32_quiet_greater_equal(x: BID32, y: BID32, flags: &mut u32) -> bool {
+  unsafe { __bid32_quiet_greater_equal(x, y, flags) != 0 }
+}
+
+/// Returns `true` if `x < y`, does not signal invalid exception for quiet NaNs.
+pub fn bid32_quiet_less(x: BID32, y: BID32, flags: &mut u32) -> bool {
+  unsafe { __bid32_quiet_less(x, y, flags) != 0 }
+}
+
+/// Returns `true` if `x <= y`, does not signal invalid exception for quiet NaNs.
+pub fn bid32_quiet_less_equal(x: BID32, y: BID32, flags: &mut u32) -> bool {
+  unsafe { __bid32_quiet_less_equal(x, y, flags) != 0 }
+}
+
+/// Returns `x * 10^n`.
+pub fn bid32_scalbn(x: BID32, n: i32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_scalbn(x, n, round, flags) }
+}
+
+/// Returns a result of decimal floating-point subtraction.
+pub fn bid32_sub(x: BID32, y: BID32, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid32_sub(x, y, round, flags) }
+}
+
+/// Converts 32-bit decimal floating-point value to string format.
+pub fn bid32_to_string(x: BID32, flags: &mut u32) -> String {
+  let mut buf = [0_u8; 64];
+  unsafe {
+    __bid32_to_string(buf.as_mut_ptr() as *mut c_char, x, flags);
+    CStr::from_ptr(buf.as_ptr() as *const c_char)
+      .to_string_lossy()
+      .into_owned()
+  }
+}
+
+/// Converts 32-bit decimal floating-point value to 128-bit decimal floating-point format.
+pub fn bid32_to_bid128(x: BID32, flags: &mut u32) -> BID128 {
+  unsafe { __bid32_to_bid128(x, flags) }
+}
+
+/// Converts 32-bit decimal floating-point value to 64-bit decimal floating-point format.
+pub fn bid32_to_bid64(x: BID32, flags: &mut u32) -> BID64 {
+  unsafe { __bid32_to_bid64(x, flags) }
+}
@@ -0,0 +1,511 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Decimal arithmetic context.
+
+use crate::bid128::{
+  bid128_cbrt, bid128_exp10, bid128_exp2, bid128_expm1, bid128_hypot,
+  bid128_log10, bid128_log1p, bid128_log2, bid128_unpack, Unpacked,
+};
+use crate::power::{bid128_powi, cmp_hypot, hypot_directed};
+use crate::{Decimal128, Flags, RoundingMode};
+use dfp_number_sys::*;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Returns `n` when `a` equals exactly `10` raised to the power of `n`.
+// `u128::is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn power_of_ten(a: Decimal128) -> Option<i32> {
+  let Unpacked::Finite {
+    negative: false,
+    mut coefficient,
+    mut exponent,
+  } = bid128_unpack(a.0)
+  else {
+    return None;
+  };
+  while coefficient >= 10 && coefficient % 10 == 0 {
+    coefficient /= 10;
+    exponent += 1;
+  }
+  (coefficient == 1).then_some(exponent)
+}
+
+thread_local! {
+  /// Context of the current thread, consulted by operators on [Decimal128].
+  static CURRENT: Cell<Context> = Cell::new(Context::default());
+}
+
+/// Environment for decimal arithmetic, modelled after the context
+/// of the General Decimal Arithmetic Specification.
+///
+/// A context bundles the rounding mode applied to results,
+/// the status flags accumulated by all operations performed through it,
+/// and the traps, i.e. flags that cause a panic when raised,
+/// or a call to the trap handler when one is set.
+///
+/// Each thread has its own current context, consulted by operators
+/// like `+` or `/` on [Decimal128], see [with_context].
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{Context, Decimal128, Flags, RoundingMode};
+///
+/// let mut ctx = Context::new(RoundingMode::TowardZero);
+/// let x = ctx.divide(Decimal128::from(2_u8), Decimal128::from(3_u8));
+/// assert_eq!("0.6666666666666666666666666666666666", x.to_string());
+/// assert_eq!(Flags::INEXACT, ctx.flags());
+/// let y = ctx.quantize(x, Decimal128::new(1, 2));
+/// assert_eq!("0.66", y.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Context {
+  /// Rounding mode applied to results.
+  rounding: RoundingMode,
+  /// Flags raised by operations since the last call to [Context::clear_flags].
+  flags: Flags,
+  /// Flags that are trapped when raised.
+  traps: Flags,
+  /// Function called when a trapped flag is raised, instead of panicking.
+  trap_handler: Option<TrapHandler>,
+}
+
+impl Context {
+  /// Creates a context with given rounding mode, no flags and no traps.
+  pub fn new(rounding: RoundingMode) -> Self {
+    Self {
+      rounding,
+      ..Default::default()
+    }
+  }
+  /// Returns this context with given traps enabled.
+  pub fn with_traps(mut self, traps: Flags) -> Self {
+    self.traps = traps;
+    self
+  }
+  /// Returns the rounding mode.
+  pub fn rounding(&self) -> RoundingMode {
+    self.rounding
+  }
+  /// Sets the rounding mode.
+  pub fn set_rounding(&mut self, rounding: RoundingMode) {
+    self.rounding = rounding;
+  }
+  /// Returns flags raised since the context was created or flags were cleared.
+  pub fn flags(&self) -> Flags {
+    self.flags
+  }
+  /// Clears all raised flags.
+  pub fn clear_flags(&mut self) {
+    self.flags = Flags::empty();
+  }
+  /// Returns enabled traps.
+  pub fn traps(&self) -> Flags {
+    self.traps
+  }
+  /// Sets enabled traps.
+  pub fn set_traps(&mut self, traps: Flags) {
+    self.traps = traps;
+  }
+  /// Returns this context with given trap handler.
+  pub fn with_trap_handler(mut self, handler: TrapHandler) -> Self {
+    self.trap_handler = Some(handler);
+    self
+  }
+  /// Returns the trap handler.
+  pub fn trap_handler(&self) -> Option<TrapHandler> {
+    self.trap_handler
+  }
+  /// Sets the trap handler, `None` restores panicking on traps.
+  pub fn set_trap_handler(&mut self, handler: Option<TrapHandler>) {
+    self.trap_handler = handler;
+  }
+  /// Returns the sum `a + b`.
+  pub fn add(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_add(a.0, b.0, self.rounding.bits(), &mut flags));
+    self.raise("add", &[a, b], r, flags)
+  }
+  /// Returns the difference `a - b`.
+  pub fn subtract(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_sub(a.0, b.0, self.rounding.bits(), &mut flags));
+    self.raise("subtract", &[a, b], r, flags)
+  }
+  /// Returns the product `a * b`.
+  pub fn multiply(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_mul(a.0, b.0, self.rounding.bits(), &mut flags));
+    self.raise("multiply", &[a, b], r, flags)
+  }
+  /// Returns the quotient `a / b`.
+  pub fn divide(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_div(a.0, b.0, self.rounding.bits(), &mut flags));
+    self.raise("divide", &[a, b], r, flags)
+  }
+  /// Returns `a` rounded to the exponent of `q`.
+  pub fn quantize(&mut self, a: Decimal128, q: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r =
+      Decimal128(bid128_quantize(a.0, q.0, self.rounding.bits(), &mut flags));
+    self.raise("quantize", &[a, q], r, flags)
+  }
+  /// Returns `a` rounded to `dp` decimal places.
+  pub fn round_dp(&mut self, a: Decimal128, dp: i32) -> Decimal128 {
+    self.quantize(a, Decimal128::new(1, dp))
+  }
+  /// Returns the natural logarithm of `a`.
+  pub fn ln(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_log(a.0, self.rounding.bits(), &mut flags));
+    self.raise("ln", &[a], r, flags)
+  }
+  /// Returns `e` raised to the power of `a`.
+  pub fn exp(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_exp(a.0, self.rounding.bits(), &mut flags));
+    self.raise("exp", &[a], r, flags)
+  }
+  /// Returns the base 10 logarithm of `a`.
+  pub fn log10(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    // the logarithm of an exact power of ten is an exact integer,
+    // the library would raise the inexact flag for some of them
+    let r = match power_of_ten(a) {
+      Some(n) => Decimal128(bid128_from_int32(n)),
+      None => Decimal128(bid128_log10(a.0, self.rounding.bits(), &mut flags)),
+    };
+    self.raise("log10", &[a], r, flags)
+  }
+  /// Returns the base 2 logarithm of `a`.
+  pub fn log2(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_log2(a.0, self.rounding.bits(), &mut flags));
+    self.raise("log2", &[a], r, flags)
+  }
+  /// Returns the natural logarithm of one plus `a`.
+  pub fn log1p(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_log1p(a.0, self.rounding.bits(), &mut flags));
+    self.raise("log1p", &[a], r, flags)
+  }
+  /// Returns 10 raised to the power of `a`.
+  pub fn exp10(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_exp10(a.0, self.rounding.bits(), &mut flags));
+    self.raise("exp10", &[a], r, flags)
+  }
+  /// Returns 2 raised to the power of `a`.
+  pub fn exp2(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_exp2(a.0, self.rounding.bits(), &mut flags));
+    self.raise("exp2", &[a], r, flags)
+  }
+  /// Returns `e` raised to the power of `a`, minus one.
+  pub fn expm1(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_expm1(a.0, self.rounding.bits(), &mut flags));
+    self.raise("expm1", &[a], r, flags)
+  }
+  /// Returns `a` raised to the power of `b`.
+  pub fn pow(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_pow(a.0, b.0, self.rounding.bits(), &mut flags));
+    self.raise("pow", &[a, b], r, flags)
+  }
+  /// Returns `a` raised to the integer power of `n`.
+  ///
+  /// The exact power is computed by repeated squaring and rounded only once,
+  /// unless its coefficient has more than a thousand digits.
+  pub fn powi(&mut self, a: Decimal128, n: i32) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_powi(a.0, n, self.rounding.bits(), &mut flags));
+    self.raise("powi", &[a, Decimal128::from(n)], r, flags)
+  }
+  /// Returns the square root of `a`.
+  pub fn sqrt(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_sqrt(a.0, self.rounding.bits(), &mut flags));
+    self.raise("sqrt", &[a], r, flags)
+  }
+  /// Returns the cube root of `a`.
+  pub fn cbrt(&mut self, a: Decimal128) -> Decimal128 {
+    let mut flags = FB_CLEAR;
+    let r = Decimal128(bid128_cbrt(a.0, self.rounding.bits(), &mut flags));
+    self.raise("cbrt", &[a], r, flags)
+  }
+  /// Returns the length of the hypotenuse `sqrt(a * a + b * b)`,
+  /// computed without intermediate overflow or underflow.
+  pub fn hypot(&mut self, a: Decimal128, b: Decimal128) -> Decimal128 {
+    let round = self.rounding.bits();
+    let mut flags = FB_CLEAR;
+    // the library result may be off by one unit in the last place in directed
+    // rounding modes, so the square root of the sum is used whenever it is exact
+    let sum = (bid128_is_finite(a.0) && bid128_is_finite(b.0))
+      .then(|| {
+        let mut exact = FB_CLEAR;
+        let aa = bid128_mul(a.0, a.0, round, &mut exact);
+        let bb = bid128_mul(b.0, b.0, round, &mut exact);
+        let sum = bid128_add(aa, bb, round, &mut exact);
+        (exact == FB_CLEAR).then_some(sum)
+      })
+      .flatten();
+    let r = match sum {
+      Some(sum) => Decimal128(bid128_sqrt(sum, round, &mut flags)),
+      None => {
+        let mut r = bid128_hypot(a.0, b.0, round, &mut flags);
+        // the library neither rounds in the requested direction
+        // nor reports inexact results of hypot
+        let finite = bid128_is_finite(a.0) && bid128_is_finite(b.0);
+        if finite {
+          match self.rounding {
+            RoundingMode::Upward => r = hypot_directed(a.0, b.0, r, true),
+            RoundingMode::Downward | RoundingMode::TowardZero => {
+              r = hypot_directed(a.0, b.0, r, false)
+            }
+            _ => {}
+          }
+        }
+        if bid128_is_finite(r) {
+          if cmp_hypot(a.0, b.0, r) != Some(Ordering::Equal) {
+            flags |= FB_INEXACT;
+          }
+        } else if finite {
+          flags |= FB_OVERFLOW | FB_INEXACT;
+        }
+        Decimal128(r)
+      }
+    };
+    self.raise("hypot", &[a, b], r, flags)
+  }
+  /// Accumulates flags raised by an operation and handles trapped flags.
+  ///
+  /// When any of the raised flags is trapped, the trap handler is called
+  /// or, when no handler is set, the thread panics.
+  pub(crate) fn raise(
+    &mut self,
+    operation: &'static str,
+    operands: &[Decimal128],
+    result: Decimal128,
+    flags: u32,
+  ) -> Decimal128 {
+    let flags = Flags::from_bits_truncate(flags);
+    self.flags |= flags;
+    let trapped = flags & self.traps;
+    if !trapped.is_empty() {
+      let trap = Trap {
+        operation,
+        operands,
+        result,
+        flags: trapped,
+      };
+      match self.trap_handler {
+        Some(handler) => handler(&trap),
+        None => panic!("{}", trap),
+      }
+    }
+    result
+  }
+}
+
+/// Function called when an operation raises a trapped flag.
+///
+/// The handler may log the condition, collect it or panic; when it returns,
+/// the operation returns its result as if the flag was not trapped.
+/// Operations performed by the handler raise flags in the current context
+/// as usual, but should not raise trapped flags.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{set_context, Context, Decimal128, Flags, Trap};
+///
+/// fn log(trap: &Trap) {
+///   eprintln!("{}", trap);
+/// }
+///
+/// if cfg!(debug_assertions) {
+///   let traps = Flags::INVALID | Flags::INEXACT;
+///   set_context(Context::default().with_traps(traps).with_trap_handler(log));
+/// }
+/// let x = Decimal128::one() / Decimal128::from(3_u8);
+/// ```
+pub type TrapHandler = fn(&Trap<'_>);
+
+/// Details of an operation that raised a trapped flag.
+#[derive(Debug, Copy, Clone)]
+pub struct Trap<'a> {
+  operation: &'static str,
+  operands: &'a [Decimal128],
+  result: Decimal128,
+  flags: Flags,
+}
+
+impl Trap<'_> {
+  /// Returns the name of the operation, like `add` or `divide`.
+  pub fn operation(&self) -> &'static str {
+    self.operation
+  }
+  /// Returns the operands of the operation.
+  pub fn operands(&self) -> &[Decimal128] {
+    self.operands
+  }
+  /// Returns the result of the operation.
+  pub fn result(&self) -> Decimal128 {
+    self.result
+  }
+  /// Returns the raised flags that are trapped.
+  pub fn flags(&self) -> Flags {
+    self.flags
+  }
+}
+
+impl fmt::Display for Trap<'_> {
+  /// Converts [Trap] into human readable string,
+  /// like `decimal operation divide(1, 0) trapped: division by zero`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "decimal operation {}(", self.operation)?;
+    for (i, operand) in self.operands.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{}", operand)?;
+    }
+    write!(f, ") trapped: {}", self.flags)
+  }
+}
+
+/// Returns a copy of the current thread context.
+pub fn get_context() -> Context {
+  CURRENT.with(|current| current.get())
+}
+
+/// Replaces the current thread context.
+pub fn set_context(ctx: Context) {
+  CURRENT.with(|current| current.set(ctx));
+}
+
+/// Runs `f` with `ctx` as the current thread context and restores
+/// the previous context afterwards.
+///
+/// Returns the result of `f` and the context as left by `f`,
+/// holding all flags raised in the block.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{with_context, Context, Decimal128, Flags, RoundingMode};
+///
+/// let (x, ctx) = with_context(Context::new(RoundingMode::Upward), || {
+///   Decimal128::from(2_u8) / Decimal128::from(3_u8)
+/// });
+/// assert_eq!("0.6666666666666666666666666666666667", x.to_string());
+/// assert_eq!(Flags::INEXACT, ctx.flags());
+/// ```
+pub fn with_context<R>(ctx: Context, f: impl FnOnce() -> R) -> (R, Context) {
+  let guard = local_context(ctx);
+  let result = f();
+  (result, guard.context())
+}
+
+/// Makes `ctx` the current thread context until the returned guard is dropped,
+/// then the previous context is restored.
+pub fn local_context(ctx: Context) -> ContextGuard {
+  let previous = get_context();
+  set_context(ctx);
+  ContextGuard {
+    previous,
+    _not_send: PhantomData,
+  }
+}
+
+/// Guard restoring the previous thread context when dropped, see [local_context].
+#[must_use = "the previous context is restored as soon as the guard is dropped"]
+pub struct ContextGuard {
+  /// Context to be restored.
+  previous: Context,
+  /// The guard must be dropped on the thread that created it.
+  _not_send: PhantomData<*const ()>,
+}
+
+impl ContextGuard {
+  /// Returns a copy of the current thread context.
+  pub fn context(&self) -> Context {
+    get_context()
+  }
+  /// Returns flags raised in the current thread context.
+  pub fn flags(&self) -> Flags {
+    get_context().flags()
+  }
+}
+
+impl Drop for ContextGuard {
+  /// Restores the previous thread context.
+  fn drop(&mut self) {
+    set_context(self.previous);
+  }
+}
+
+/// Runs `f` with a copy of the current thread context, adding flags raised
+/// by `f` to the thread context even when `f` panics on a trap.
+///
+/// Only raised flags are written back, so flags raised in the meantime,
+/// like by operations performed in a trap handler, are kept.
+pub(crate) fn with_current<R>(f: impl FnOnce(&mut Context) -> R) -> R {
+  with_rounding(get_context().rounding, f)
+}
+
+/// Runs `f` like [with_current], with `rounding` in place of the rounding mode
+/// of the current thread context.
+pub(crate) fn with_rounding<R>(
+  rounding: RoundingMode,
+  f: impl FnOnce(&mut Context) -> R,
+) -> R {
+  /// Adds flags raised since `initial` to the thread context when dropped.
+  struct WriteBack {
+    ctx: Context,
+    initial: Flags,
+  }
+  impl Drop for WriteBack {
+    fn drop(&mut self) {
+      let mut ctx = get_context();
+      ctx.flags |= self.ctx.flags & !self.initial;
+      set_context(ctx);
+    }
+  }
+  let ctx = Context {
+    rounding,
+    ..get_context()
+  };
+  let mut write_back = WriteBack {
+    ctx,
+    initial: ctx.flags,
+  };
+  f(&mut write_back.ctx)
+}
@@ -24,10 +24,18 @@
 
 //! 128-bit decimal floating point arithmetic.
 
+use crate::bid128::*;
+use crate::bid32::*;
+use crate::bid64::*;
+use crate::{context, format, parse};
+use crate::{
+  Context, ConversionError, Decimal32, Decimal64, DecimalError, Flags,
+  NumberFormat, ParseDecimalError, RoundingMode,
+};
 use dfp_number_sys::*;
 use std::cmp::Ordering;
-use std::convert::Infallible;
 use std::fmt;
+use std::fmt::Write;
 use std::str::FromStr;
 
 #[macro_export]
@@ -39,7 +47,7 @@ macro_rules! decnum {
 
 /// 128-bit decimal value.
 #[derive(Copy, Clone)]
-pub struct Decimal128(BID128);
+pub struct Decimal128(pub(crate) BID128);
 
 impl Default for Decimal128 {
   /// The default value of [Decimal128] is `0` (zero).
@@ -58,103 +66,702 @@ impl fmt::Debug for Decimal128 {
 
 impl fmt::Display for Decimal128 {
   /// Converts [Decimal128] into human readable string.
+  ///
+  /// When precision is given, the number is rounded according to the rounding
+  /// mode of the current thread's context, by default ties to even.
+  /// Infinities and NaNs are written as `Infinity`, `-Infinity`, `NaN` or `sNaN`,
+  /// the alternate flag (`{:#}`) adds the payload of a NaN, like `sNaN123`.
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let mut flags = FB_CLEAR;
-    let s = bid128_to_string(self.0, &mut flags);
-    let negative = s.starts_with('-');
-    let mut split = s[1..].split('E');
-    if let Some((sb, sa)) = split.next().zip(split.next()) {
-      if let Ok(exponent) = sa.parse::<isize>() {
-        let decimal_points = exponent.unsigned_abs();
-        let (mut before, mut after) = if exponent < 0 {
-          let digit_count = sb.len();
-          if digit_count <= decimal_points {
-            let before = "0".to_string();
-            let mut after = "0".repeat(decimal_points - digit_count);
-            after.push_str(sb.trim_end_matches('0'));
-            (before, after)
-          } else {
-            let before = sb[..digit_count - decimal_points].to_string();
-            let after = sb[digit_count - decimal_points..]
-              .trim_end_matches('0')
-              .to_string();
-            (before, after)
-          }
-        } else {
-          let mut before = sb.to_string();
-          before.push_str(&"0".repeat(decimal_points));
-          let after = "".to_string();
-          (before, after)
-        };
-        if let Some(precision) = f.precision() {
-          if after.len() < precision {
-            after.push_str(&"0".repeat(precision - after.len()));
-          } else {
-            after = after[0..precision].to_string();
-          }
-        }
-        if !after.is_empty() {
-          before.push('.');
-          before.push_str(&after);
-        }
-        return f.pad_integral(!negative, "", &before);
+    match bid128_unpack(self.0) {
+      Unpacked::Finite {
+        negative,
+        coefficient,
+        exponent,
+      } => {
+        let rounding = context::get_context().rounding();
+        format::Plain::new(
+          negative,
+          coefficient,
+          exponent,
+          f.precision(),
+          rounding,
+        )
+        .fmt(f)
+      }
+      special => format::special(special, f).unwrap(),
+    }
+  }
+}
+
+impl fmt::LowerExp for Decimal128 {
+  /// Converts [Decimal128] into string in scientific notation, like `1.2345e-7`,
+  /// rounding like [Display](fmt::Display) when precision is given.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match bid128_unpack(self.0) {
+      Unpacked::Finite {
+        negative,
+        coefficient,
+        exponent,
+      } => {
+        let rounding = context::get_context().rounding();
+        format::exponential(negative, coefficient, exponent, false, rounding, f)
       }
+      special => format::special(special, f).unwrap(),
+    }
+  }
+}
+
+impl fmt::UpperExp for Decimal128 {
+  /// Converts [Decimal128] into string in scientific notation, like `1.2345E-7`,
+  /// rounding like [Display](fmt::Display) when precision is given.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match bid128_unpack(self.0) {
+      Unpacked::Finite {
+        negative,
+        coefficient,
+        exponent,
+      } => {
+        let rounding = context::get_context().rounding();
+        format::exponential(negative, coefficient, exponent, true, rounding, f)
+      }
+      special => format::special(special, f).unwrap(),
     }
-    f.pad(&s)
   }
 }
 
 impl Decimal128 {
-  ///
+  /// Maximum number of bytes written by [Decimal128::write_to],
+  /// reached by a negative number with 34 digits and the smallest exponent.
+  pub const MAX_STR_LEN: usize = 6179;
+  /// Returns a [Decimal128] with value `n * 10^(-s)`.
   pub fn new(n: i64, s: i32) -> Self {
     Self(bid128_scalbn(bid128_from_int64(n), -s))
   }
-  ///
+  /// Returns a [Decimal128] with value `0`.
   pub fn zero() -> Self {
     Self(bid128_from_uint32(0))
   }
-  ///
+  /// Returns `true` when this number is `+0` or `-0`.
   pub fn is_zero(&self) -> bool {
     bid128_is_zero(self.0)
   }
-  ///
+  /// Returns `true` when this number is a NaN.
+  pub fn is_nan(&self) -> bool {
+    bid128_is_nan(self.0)
+  }
+  /// Returns `true` when this number is positive or negative infinity.
+  pub fn is_infinite(&self) -> bool {
+    bid128_is_inf(self.0)
+  }
+  /// Returns `true` when this number is neither infinite nor NaN.
+  pub fn is_finite(&self) -> bool {
+    bid128_is_finite(self.0)
+  }
+  /// Returns `true` when this number has negative sign, including `-0` and negative NaNs.
+  pub fn is_sign_negative(&self) -> bool {
+    bid128_is_signed(self.0)
+  }
+  /// Returns a [Decimal128] with value `1`.
   pub fn one() -> Self {
     Self(bid128_from_uint32(1))
   }
-  ///
+  /// Returns a [Decimal128] with value `2`.
   pub fn two() -> Self {
     Self(bid128_from_uint32(2))
   }
-  ///
+  /// Returns a [Decimal128] with value `10`.
   pub fn ten() -> Self {
     Self(bid128_from_uint32(10))
   }
-  ///
+  /// Returns a [Decimal128] with value `100`.
   pub fn one_hundred() -> Self {
     Self(bid128_from_uint32(100))
   }
-  ///
+  /// Returns a [Decimal128] with value `1000`.
   pub fn one_thousand() -> Self {
     Self(bid128_from_uint32(1000))
   }
-  ///
+  /// Returns the natural logarithm of this number,
+  /// computed in the current thread context.
   pub fn ln(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.ln(*self))
+  }
+  /// Returns `e` raised to the power of this number,
+  /// computed in the current thread context.
+  pub fn exp(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.exp(*self))
+  }
+  /// Returns the base 10 logarithm of this number,
+  /// computed in the current thread context.
+  ///
+  /// The logarithm of an exact power of ten is an exact integer.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::{Decimal128, Flags};
+  ///
+  /// assert_eq!("-3", Decimal128::from("0.001").log10().to_string());
+  /// let (y, flags) = Decimal128::from("1E+6000").log10_with_flags();
+  /// assert_eq!("6000", y.to_string());
+  /// assert_eq!(Flags::empty(), flags);
+  /// ```
+  pub fn log10(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.log10(*self))
+  }
+  /// Returns the base 2 logarithm of this number,
+  /// computed in the current thread context.
+  pub fn log2(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.log2(*self))
+  }
+  /// Returns the natural logarithm of one plus this number,
+  /// computed in the current thread context.
+  pub fn log1p(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.log1p(*self))
+  }
+  /// Returns 10 raised to the power of this number,
+  /// computed in the current thread context.
+  pub fn exp10(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.exp10(*self))
+  }
+  /// Returns 2 raised to the power of this number,
+  /// computed in the current thread context.
+  pub fn exp2(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.exp2(*self))
+  }
+  /// Returns `e` raised to the power of this number, minus one,
+  /// computed in the current thread context.
+  pub fn expm1(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.expm1(*self))
+  }
+  /// Returns this number raised to the power of `exp`,
+  /// computed in the current thread context.
+  pub fn pow(&self, exp: Self) -> Decimal128 {
+    context::with_current(|ctx| ctx.pow(*self, exp))
+  }
+  /// Returns this number raised to the integer power of `n`,
+  /// computed in the current thread context.
+  ///
+  /// The exact power is rounded only once, unless its coefficient
+  /// has more than a thousand digits.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::{Decimal128, Flags};
+  ///
+  /// let x = Decimal128::from("1.05");
+  /// assert_eq!("1.157625", x.powi(3).to_string());
+  /// assert_eq!("0.0625", Decimal128::from(2).powi(-4).to_string());
+  /// let (y, flags) = x.powi_with_flags(30);
+  /// assert_eq!("4.321942375150662009157288198886473", y.to_string());
+  /// assert_eq!(Flags::INEXACT, flags);
+  /// ```
+  pub fn powi(&self, n: i32) -> Decimal128 {
+    context::with_current(|ctx| ctx.powi(*self, n))
+  }
+  /// Returns the square root of this number,
+  /// computed in the current thread context.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::Decimal128;
+  ///
+  /// assert_eq!("1.2", Decimal128::from("1.44").sqrt().to_string());
+  /// assert!(Decimal128::from(-1).sqrt().is_nan());
+  /// ```
+  pub fn sqrt(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.sqrt(*self))
+  }
+  /// Returns the cube root of this number,
+  /// computed in the current thread context.
+  pub fn cbrt(&self) -> Decimal128 {
+    context::with_current(|ctx| ctx.cbrt(*self))
+  }
+  /// Returns the length of the hypotenuse of a right triangle with legs
+  /// of this number and `other`, computed in the current thread context.
+  pub fn hypot(&self, other: Self) -> Decimal128 {
+    context::with_current(|ctx| ctx.hypot(*self, other))
+  }
+  /// Rounds this number to `dp` decimal places using the rounding mode
+  /// of the current thread context, which is half to even by default.
+  pub fn round_dp(&self, dp: i32) -> Self {
+    context::with_current(|ctx| ctx.round_dp(*self, dp))
+  }
+  /// Returns the sum of this number and `rhs`, rounded according to `rounding`.
+  ///
+  /// Like for all operations rounding according to a given mode, raised flags
+  /// are accumulated in the current thread context and its traps apply.
+  pub fn add_with(&self, rhs: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.add(*self, rhs))
+  }
+  /// Returns the difference of this number and `rhs`, rounded according to `rounding`.
+  pub fn sub_with(&self, rhs: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.subtract(*self, rhs))
+  }
+  /// Returns the product of this number and `rhs`, rounded according to `rounding`.
+  pub fn mul_with(&self, rhs: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.multiply(*self, rhs))
+  }
+  /// Returns the quotient of this number and `rhs`, rounded according to `rounding`.
+  pub fn div_with(&self, rhs: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.divide(*self, rhs))
+  }
+  /// Returns the natural logarithm of this number, rounded according to `rounding`.
+  pub fn ln_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.ln(*self))
+  }
+  /// Returns `e` raised to the power of this number, rounded according to `rounding`.
+  pub fn exp_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.exp(*self))
+  }
+  /// Returns the base 10 logarithm of this number, rounded according to `rounding`.
+  pub fn log10_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.log10(*self))
+  }
+  /// Returns the base 2 logarithm of this number, rounded according to `rounding`.
+  pub fn log2_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.log2(*self))
+  }
+  /// Returns the natural logarithm of one plus this number, rounded according to `rounding`.
+  pub fn log1p_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.log1p(*self))
+  }
+  /// Returns 10 raised to the power of this number, rounded according to `rounding`.
+  pub fn exp10_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.exp10(*self))
+  }
+  /// Returns 2 raised to the power of this number, rounded according to `rounding`.
+  pub fn exp2_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.exp2(*self))
+  }
+  /// Returns `e` raised to the power of this number, minus one, rounded according to `rounding`.
+  pub fn expm1_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.expm1(*self))
+  }
+  /// Returns this number raised to the power of `exp`, rounded according to `rounding`.
+  pub fn pow_with(&self, exp: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.pow(*self, exp))
+  }
+  /// Returns this number raised to the integer power of `n`, rounded according to `rounding`.
+  pub fn powi_with(&self, n: i32, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.powi(*self, n))
+  }
+  /// Returns the square root of this number, rounded according to `rounding`.
+  pub fn sqrt_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.sqrt(*self))
+  }
+  /// Returns the cube root of this number, rounded according to `rounding`.
+  pub fn cbrt_with(&self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.cbrt(*self))
+  }
+  /// Returns the length of the hypotenuse with legs of this number and `other`,
+  /// rounded according to `rounding`.
+  pub fn hypot_with(&self, other: Self, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.hypot(*self, other))
+  }
+  /// Rounds this number to `dp` decimal places according to `rounding`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::{Decimal128, RoundingMode};
+  ///
+  /// let x = Decimal128::from("2.345");
+  /// assert_eq!("2.34", x.round_dp_with(2, RoundingMode::NearestEven).to_string());
+  /// assert_eq!("2.35", x.round_dp_with(2, RoundingMode::NearestAway).to_string());
+  /// assert_eq!("-2.34", (-x).round_dp_with(2, RoundingMode::Upward).to_string());
+  /// ```
+  pub fn round_dp_with(&self, dp: i32, rounding: RoundingMode) -> Self {
+    context::with_rounding(rounding, |ctx| ctx.round_dp(*self, dp))
+  }
+  /// Returns the sum of this number and `rhs` together with raised flags.
+  ///
+  /// The rounding mode of the current thread context is used,
+  /// flags are not accumulated in that context and traps are not checked.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::{Decimal128, Flags};
+  ///
+  /// let x = Decimal128::from("9.999E+6144");
+  /// let (y, flags) = x.add_with_flags(Decimal128::from("1E+6141"));
+  /// assert!(y.is_infinite());
+  /// assert_eq!(Flags::OVERFLOW | Flags::INEXACT, flags);
+  /// ```
+  pub fn add_with_flags(&self, rhs: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.add(*self, rhs))
+  }
+  /// Returns the difference of this number and `rhs` together with raised flags.
+  pub fn sub_with_flags(&self, rhs: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.subtract(*self, rhs))
+  }
+  /// Returns the product of this number and `rhs` together with raised flags.
+  pub fn mul_with_flags(&self, rhs: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.multiply(*self, rhs))
+  }
+  /// Returns the quotient of this number and `rhs` together with raised flags.
+  pub fn div_with_flags(&self, rhs: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.divide(*self, rhs))
+  }
+  /// Returns the natural logarithm of this number together with raised flags.
+  pub fn ln_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.ln(*self))
+  }
+  /// Returns `e` raised to the power of this number together with raised flags.
+  pub fn exp_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.exp(*self))
+  }
+  /// Returns the base 10 logarithm of this number together with raised flags.
+  pub fn log10_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.log10(*self))
+  }
+  /// Returns the base 2 logarithm of this number together with raised flags.
+  pub fn log2_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.log2(*self))
+  }
+  /// Returns the natural logarithm of one plus this number together with raised flags.
+  pub fn log1p_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.log1p(*self))
+  }
+  /// Returns 10 raised to the power of this number together with raised flags.
+  pub fn exp10_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.exp10(*self))
+  }
+  /// Returns 2 raised to the power of this number together with raised flags.
+  pub fn exp2_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.exp2(*self))
+  }
+  /// Returns `e` raised to the power of this number, minus one together with raised flags.
+  pub fn expm1_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.expm1(*self))
+  }
+  /// Returns this number raised to the power of `exp` together with raised flags.
+  pub fn pow_with_flags(&self, exp: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.pow(*self, exp))
+  }
+  /// Returns this number raised to the integer power of `n` together with raised flags.
+  pub fn powi_with_flags(&self, n: i32) -> (Self, Flags) {
+    with_flags(|ctx| ctx.powi(*self, n))
+  }
+  /// Returns the square root of this number together with raised flags.
+  pub fn sqrt_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.sqrt(*self))
+  }
+  /// Returns the cube root of this number together with raised flags.
+  pub fn cbrt_with_flags(&self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.cbrt(*self))
+  }
+  /// Returns the length of the hypotenuse with legs of this number and `other`
+  /// together with raised flags.
+  pub fn hypot_with_flags(&self, other: Self) -> (Self, Flags) {
+    with_flags(|ctx| ctx.hypot(*self, other))
+  }
+  /// Rounds this number to `dp` decimal places, returns the result together with raised flags.
+  pub fn round_dp_with_flags(&self, dp: i32) -> (Self, Flags) {
+    with_flags(|ctx| ctx.round_dp(*self, dp))
+  }
+  /// Returns the sum of this number and `rhs`, or an error when the result
+  /// is not exact or not finite.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::{Decimal128, DecimalError};
+  ///
+  /// let x = Decimal128::from("0.1");
+  /// assert_eq!("0.3", x.checked_add(Decimal128::from("0.2")).unwrap().to_string());
+  /// let y = Decimal128::from("1E+34");
+  /// assert_eq!(Err(DecimalError::Inexact), x.checked_add(y));
+  /// ```
+  pub fn checked_add(&self, rhs: Self) -> Result<Self, DecimalError> {
+    checked(self.add_with_flags(rhs))
+  }
+  /// Returns the difference of this number and `rhs`, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_sub(&self, rhs: Self) -> Result<Self, DecimalError> {
+    checked(self.sub_with_flags(rhs))
+  }
+  /// Returns the product of this number and `rhs`, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_mul(&self, rhs: Self) -> Result<Self, DecimalError> {
+    checked(self.mul_with_flags(rhs))
+  }
+  /// Returns the quotient of this number and `rhs`, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_div(&self, rhs: Self) -> Result<Self, DecimalError> {
+    checked(self.div_with_flags(rhs))
+  }
+  /// Returns the natural logarithm of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_ln(&self) -> Result<Self, DecimalError> {
+    checked(self.ln_with_flags())
+  }
+  /// Returns `e` raised to the power of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_exp(&self) -> Result<Self, DecimalError> {
+    checked(self.exp_with_flags())
+  }
+  /// Returns the base 10 logarithm of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_log10(&self) -> Result<Self, DecimalError> {
+    checked(self.log10_with_flags())
+  }
+  /// Returns the base 2 logarithm of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_log2(&self) -> Result<Self, DecimalError> {
+    checked(self.log2_with_flags())
+  }
+  /// Returns the natural logarithm of one plus this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_log1p(&self) -> Result<Self, DecimalError> {
+    checked(self.log1p_with_flags())
+  }
+  /// Returns 10 raised to the power of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_exp10(&self) -> Result<Self, DecimalError> {
+    checked(self.exp10_with_flags())
+  }
+  /// Returns 2 raised to the power of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_exp2(&self) -> Result<Self, DecimalError> {
+    checked(self.exp2_with_flags())
+  }
+  /// Returns `e` raised to the power of this number, minus one, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_expm1(&self) -> Result<Self, DecimalError> {
+    checked(self.expm1_with_flags())
+  }
+  /// Returns this number raised to the power of `exp`, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_pow(&self, exp: Self) -> Result<Self, DecimalError> {
+    checked(self.pow_with_flags(exp))
+  }
+  /// Returns this number raised to the integer power of `n`, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_powi(&self, n: i32) -> Result<Self, DecimalError> {
+    checked(self.powi_with_flags(n))
+  }
+  /// Returns the square root of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_sqrt(&self) -> Result<Self, DecimalError> {
+    checked(self.sqrt_with_flags())
+  }
+  /// Returns the cube root of this number, or an error when the result
+  /// is not exact or not finite.
+  pub fn checked_cbrt(&self) -> Result<Self, DecimalError> {
+    checked(self.cbrt_with_flags())
+  }
+  /// Returns the length of the hypotenuse with legs of this number and `other`,
+  /// or an error when the result is not exact or not finite.
+  pub fn checked_hypot(&self, other: Self) -> Result<Self, DecimalError> {
+    checked(self.hypot_with_flags(other))
+  }
+  /// Returns this number with `dp` decimal places, or an error when
+  /// nonzero digits would be discarded or the result is not finite.
+  pub fn checked_round_dp(&self, dp: i32) -> Result<Self, DecimalError> {
+    checked(self.round_dp_with_flags(dp))
+  }
+  /// Converts [Decimal128] from string, invalid input gives NaN and input with
+  /// more than 34 significant digits is rounded to nearest, ties to even.
+  ///
+  /// Use [str::parse] to reject such input with a [ParseDecimalError].
+  pub fn from_str_lossy(s: &str) -> Self {
+    // the library can not be given input with an interior NUL
+    if s.contains('\0') {
+      return Self(bid128_nan(false, false, 0));
+    }
+    let mut flags = FB_CLEAR;
+    Self(bid128_from_string(s, RM_NEAREST_EVEN, &mut flags))
+  }
+  /// Converts [Decimal128] from ASCII bytes, accepting the same input as [str::parse].
+  ///
+  /// Bytes are parsed directly, without UTF-8 validation and without allocation,
+  /// plain input like `-1234.56` with at most 34 digits is parsed in a single pass.
+  pub fn parse_bytes(bytes: &[u8]) -> Result<Self, ParseDecimalError> {
+    parse::parse_bid128(bytes).map(Self)
+  }
+  /// Converts [Decimal128] from string written according to given locale `format`.
+  ///
+  /// The result is exact, input that does not match the format, has invalid
+  /// digit grouping or can not be represented exactly is rejected.
+  pub fn parse_with_format(
+    s: &str,
+    format: &NumberFormat,
+  ) -> Result<Self, ParseDecimalError> {
+    format.parse(s)
+  }
+  /// Converts [Decimal128] from string, returns the result together with raised flags.
+  ///
+  /// Invalid input gives NaN without raising any flag, input with more than
+  /// 34 significant digits is rounded and raises [Flags::INEXACT].
+  /// Input with an interior NUL gives NaN and raises [Flags::INVALID].
+  pub fn from_str_with_flags(s: &str) -> (Self, Flags) {
+    // the library can not be given input with an interior NUL
+    if s.contains('\0') {
+      return (Self(bid128_nan(false, false, 0)), Flags::INVALID);
+    }
+    let rounding = context::get_context().rounding();
     let mut flags = FB_CLEAR;
-    Self(bid128_log(self.0, RM_NEAREST_EVEN, &mut flags))
+    let value = Self(bid128_from_string(s, rounding.bits(), &mut flags));
+    (value, Flags::from_bits_truncate(flags))
   }
+  /// Returns the string representation of this number defined by the
+  /// *to-scientific-string* operation of the General Decimal Arithmetic
+  /// Specification, like `123.45`, `1.20E+7` or `-Infinity`.
   ///
-  pub fn exp(&self) -> Decimal128 {
+  /// The exponent (quantum) is preserved, so [str::parse] gives back the same number.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::Decimal128;
+  ///
+  /// let x = Decimal128::new(1200, 11);
+  /// assert_eq!("1.200E-8", x.to_sci_string());
+  /// assert_eq!("12.00E-9", x.to_eng_string());
+  /// assert_eq!(format!("{:?}", x), format!("{:?}", x.to_sci_string().parse::<Decimal128>().unwrap()));
+  /// ```
+  pub fn to_sci_string(&self) -> String {
+    format::sci_string(bid128_unpack(self.0), false)
+  }
+  /// Returns the string representation of this number defined by the
+  /// *to-engineering-string* operation of the General Decimal Arithmetic
+  /// Specification, where the exponent is a multiple of three, like `12.00E-9`.
+  ///
+  /// The exponent (quantum) is preserved, so [str::parse] gives back the same number.
+  pub fn to_eng_string(&self) -> String {
+    format::sci_string(bid128_unpack(self.0), true)
+  }
+  /// Writes this number into `buf` in the same form as [Display](fmt::Display)
+  /// without any formatting options, returns the number of written bytes.
+  ///
+  /// No memory is allocated, a buffer of [Decimal128::MAX_STR_LEN] bytes
+  /// is always large enough.
+  ///
+  /// # Panics
+  ///
+  /// Panics when the buffer is too small.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use dfp_number::Decimal128;
+  ///
+  /// let mut buf = [0; 32];
+  /// let len = Decimal128::new(-12345, 3).write_to(&mut buf);
+  /// assert_eq!(b"-12.345", &buf[..len]);
+  /// ```
+  pub fn write_to(&self, buf: &mut [u8]) -> usize {
+    let mut writer = format::SliceWriter::new(buf);
+    let result = match bid128_unpack(self.0) {
+      Unpacked::Finite {
+        negative,
+        coefficient,
+        exponent,
+      } => {
+        let plain = format::Plain::new(
+          negative,
+          coefficient,
+          exponent,
+          None,
+          RoundingMode::NearestEven,
+        );
+        let sign = if plain.is_negative() { "-" } else { "" };
+        writer
+          .write_str(sign)
+          .and_then(|_| plain.write_unsigned(&mut writer))
+      }
+      special => {
+        let (negative, name, _) = format::special_name(special).unwrap();
+        let sign = if negative { "-" } else { "" };
+        writer.write_str(sign).and_then(|_| writer.write_str(name))
+      }
+    };
+    assert!(result.is_ok(), "buffer too small to write decimal number");
+    writer.len()
+  }
+  /// Converts this number into [Decimal64], rounding according to `rounding`.
+  ///
+  /// Returns an error holding the rounded value and the raised flags
+  /// when the conversion is inexact, overflows or underflows. Raised flags
+  /// are also accumulated in the current thread context and its traps apply.
+  pub fn to_decimal64(
+    &self,
+    rounding: RoundingMode,
+  ) -> Result<Decimal64, ConversionError<Decimal64>> {
     let mut flags = FB_CLEAR;
-    Self(bid128_exp(self.0, RM_NEAREST_EVEN, &mut flags))
+    let value = Decimal64(bid128_to_bid64(self.0, rounding.bits(), &mut flags));
+    // widening is exact, the trap reports the rounded value
+    let mut exact = FB_CLEAR;
+    let rounded = Decimal128(bid64_to_bid128(value.0, &mut exact));
+    context::with_rounding(rounding, |ctx| {
+      ctx.raise("to_decimal64", &[*self], rounded, flags)
+    });
+    if flags == FB_CLEAR {
+      Ok(value)
+    } else {
+      Err(ConversionError::new(
+        value,
+        Flags::from_bits_truncate(flags),
+      ))
+    }
   }
+  /// Converts this number into [Decimal32], rounding according to `rounding`.
   ///
-  pub fn round_dp(&self, dp: i32) -> Self {
-    let q = bid128_scalbn(Self::one().0, -dp);
+  /// Returns an error holding the rounded value and the raised flags
+  /// when the conversion is inexact, overflows or underflows. Raised flags
+  /// are also accumulated in the current thread context and its traps apply.
+  pub fn to_decimal32(
+    &self,
+    rounding: RoundingMode,
+  ) -> Result<Decimal32, ConversionError<Decimal32>> {
     let mut flags = FB_CLEAR;
-    Self(bid128_quantize(self.0, q, RM_NEAREST_EVEN, &mut flags))
+    let value = Decimal32(bid128_to_bid32(self.0, rounding.bits(), &mut flags));
+    // widening is exact, the trap reports the rounded value
+    let mut exact = FB_CLEAR;
+    let rounded = Decimal128(bid32_to_bid128(value.0, &mut exact));
+    context::with_rounding(rounding, |ctx| {
+      ctx.raise("to_decimal32", &[*self], rounded, flags)
+    });
+    if flags == FB_CLEAR {
+      Ok(value)
+    } else {
+      Err(ConversionError::new(
+        value,
+        Flags::from_bits_truncate(flags),
+      ))
+    }
+  }
+}
+
+/// Returns the value when no flag was raised and the value is finite,
+/// otherwise returns an error naming the condition.
+///
+/// Non-finite values without raised flags result from NaN or infinite
+/// operands and are reported as invalid operation.
+fn checked(
+  (value, flags): (Decimal128, Flags),
+) -> Result<Decimal128, DecimalError> {
+  match DecimalError::from_flags(flags) {
+    Some(error) => Err(error),
+    None if !value.is_finite() => Err(DecimalError::InvalidOperation),
+    None => Ok(value),
   }
 }
 
+/// Runs `f` in a fresh context with the rounding mode of the current thread context,
+/// returns the result together with flags raised by `f`.
+fn with_flags(
+  f: impl FnOnce(&mut Context) -> Decimal128,
+) -> (Decimal128, Flags) {
+  let mut ctx = Context::new(context::get_context().rounding());
+  let value = f(&mut ctx);
+  (value, ctx.flags())
+}
+
 impl std::ops::Neg for Decimal128 {
   type Output = Self;
   fn neg(self) -> Self::Output {
@@ -164,74 +771,66 @@ impl std::ops::Neg for Decimal128 {
 
 impl std::ops::Add<Self> for Decimal128 {
   type Output = Self;
-  ///
+  /// Adds two [Decimal128] values.
   fn add(self, rhs: Self) -> Self::Output {
-    let mut flags = FB_CLEAR;
-    Self(bid128_add(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+    context::with_current(|ctx| ctx.add(self, rhs))
   }
 }
 
 impl std::ops::AddAssign<Self> for Decimal128 {
-  ///
+  /// Adds a [Decimal128] value in place.
   fn add_assign(&mut self, rhs: Self) {
-    let mut flags = FB_CLEAR;
-    self.0 = bid128_add(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+    *self = context::with_current(|ctx| ctx.add(*self, rhs))
   }
 }
 
 impl std::ops::Sub<Self> for Decimal128 {
   type Output = Self;
-  ///
+  /// Subtracts two [Decimal128] values.
   fn sub(self, rhs: Self) -> Self::Output {
-    let mut flags = FB_CLEAR;
-    Self(bid128_sub(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+    context::with_current(|ctx| ctx.subtract(self, rhs))
   }
 }
 
 impl std::ops::SubAssign<Self> for Decimal128 {
-  ///
+  /// Subtracts a [Decimal128] value in place.
   fn sub_assign(&mut self, rhs: Self) {
-    let mut flags = FB_CLEAR;
-    self.0 = bid128_sub(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+    *self = context::with_current(|ctx| ctx.subtract(*self, rhs))
   }
 }
 
 impl std::ops::Mul<Self> for Decimal128 {
   type Output = Self;
-  ///
+  /// Multiplies two [Decimal128] values.
   fn mul(self, rhs: Self) -> Self::Output {
-    let mut flags = FB_CLEAR;
-    Self(bid128_mul(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+    context::with_current(|ctx| ctx.multiply(self, rhs))
   }
 }
 
 impl std::ops::MulAssign<Self> for Decimal128 {
-  ///
+  /// Multiplies by a [Decimal128] value in place.
   fn mul_assign(&mut self, rhs: Self) {
-    let mut flags = FB_CLEAR;
-    self.0 = bid128_mul(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+    *self = context::with_current(|ctx| ctx.multiply(*self, rhs))
   }
 }
 
 impl std::ops::Div<Self> for Decimal128 {
   type Output = Self;
-  ///
+  /// Divides two [Decimal128] values.
   fn div(self, rhs: Self) -> Self::Output {
-    let mut flags = FB_CLEAR;
-    Self(bid128_div(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+    context::with_current(|ctx| ctx.divide(self, rhs))
   }
 }
 
 impl std::ops::DivAssign<Self> for Decimal128 {
-  ///
+  /// Divides by a [Decimal128] value in place.
   fn div_assign(&mut self, rhs: Self) {
-    let mut flags = FB_CLEAR;
-    self.0 = bid128_div(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+    *self = context::with_current(|ctx| ctx.divide(*self, rhs))
   }
 }
 
 impl PartialEq<Self> for Decimal128 {
-  ///
+  /// Returns `true` when both values are numerically equal.
   fn eq(&self, rhs: &Self) -> bool {
     let mut flags = FB_CLEAR;
     bid128_quiet_equal(self.0, rhs.0, &mut flags)
@@ -241,34 +840,26 @@ impl PartialEq<Self> for Decimal128 {
 impl Eq for Decimal128 {}
 
 impl PartialOrd<Self> for Decimal128 {
-  ///
+  /// Compares two [Decimal128] values.
   fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-    let mut flags = FB_CLEAR;
-    if bid128_quiet_equal(self.0, rhs.0, &mut flags) {
-      return Some(Ordering::Equal);
-    }
-    flags = FB_CLEAR;
-    if bid128_quiet_less(self.0, rhs.0, &mut flags) {
-      return Some(Ordering::Less);
-    }
-    Some(Ordering::Greater)
+    Some(self.cmp(rhs))
   }
-  ///
+  /// Returns `true` when this value is less than `rhs`.
   fn lt(&self, rhs: &Self) -> bool {
     let mut flags = FB_CLEAR;
     bid128_quiet_less(self.0, rhs.0, &mut flags)
   }
-  ///
+  /// Returns `true` when this value is less than or equal to `rhs`.
   fn le(&self, rhs: &Self) -> bool {
     let mut flags = FB_CLEAR;
     bid128_quiet_less_equal(self.0, rhs.0, &mut flags)
   }
-  ///
+  /// Returns `true` when this value is greater than `rhs`.
   fn gt(&self, rhs: &Self) -> bool {
     let mut flags = FB_CLEAR;
     bid128_quiet_greater(self.0, rhs.0, &mut flags)
   }
-  ///
+  /// Returns `true` when this value is greater than or equal to `rhs`.
   fn ge(&self, rhs: &Self) -> bool {
     let mut flags = FB_CLEAR;
     bid128_quiet_greater_equal(self.0, rhs.0, &mut flags)
@@ -276,7 +867,7 @@ impl PartialOrd<Self> for Decimal128 {
 }
 
 impl Ord for Decimal128 {
-  ///
+  /// Compares two [Decimal128] values.
   fn cmp(&self, rhs: &Self) -> Ordering {
     let mut flags = FB_CLEAR;
     if bid128_quiet_equal(self.0, rhs.0, &mut flags) {
@@ -288,15 +879,15 @@ impl Ord for Decimal128 {
     }
     Ordering::Greater
   }
-  ///
+  /// Returns the greater of two [Decimal128] values.
   fn max(self, rhs: Self) -> Self
   where
     Self: Sized,
   {
     let mut flags = FB_CLEAR;
-    Self(bid128_minnum(self.0, rhs.0, &mut flags))
+    Self(bid128_maxnum(self.0, rhs.0, &mut flags))
   }
-  ///
+  /// Returns the lesser of two [Decimal128] values.
   fn min(self, rhs: Self) -> Self
   where
     Self: Sized,
@@ -307,18 +898,20 @@ impl Ord for Decimal128 {
 }
 
 impl From<&str> for Decimal128 {
+  /// Converts [Decimal128] from string, invalid input gives NaN.
   ///
+  /// This is the same as [Decimal128::from_str_lossy].
   fn from(s: &str) -> Self {
-    let mut flags = FB_CLEAR;
-    Self(bid128_from_string(s, RM_NEAREST_EVEN, &mut flags))
+    Self::from_str_lossy(s)
   }
 }
 
 impl FromStr for Decimal128 {
-  type Err = Infallible;
-  ///
+  type Err = ParseDecimalError;
+  /// Converts [Decimal128] from string, rejecting invalid input
+  /// and numbers that can not be represented exactly.
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    Ok(s.into())
+    Self::parse_bytes(s.as_bytes())
   }
 }
 
@@ -391,3 +984,19 @@ impl From<isize> for Decimal128 {
     Self(bid128_from_int64(n as i64))
   }
 }
+
+impl From<Decimal32> for Decimal128 {
+  /// Converts [Decimal128] from [Decimal32], the conversion is always exact.
+  fn from(n: Decimal32) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid32_to_bid128(n.0, &mut flags))
+  }
+}
+
+impl From<Decimal64> for Decimal128 {
+  /// Converts [Decimal128] from [Decimal64], the conversion is always exact.
+  fn from(n: Decimal64) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_to_bid128(n.0, &mut flags))
+  }
+}
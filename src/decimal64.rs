@@ -0,0 +1,410 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! 64-bit decimal floating point arithmetic.
+
+use crate::bid128::{bid128_unpack, Unpacked};
+use crate::bid32::*;
+use crate::bid64::*;
+use crate::{context, format};
+use crate::{ConversionError, Decimal32, Flags, RoundingMode};
+use dfp_number_sys::{FB_CLEAR, RM_NEAREST_EVEN};
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// 64-bit decimal value.
+#[derive(Copy, Clone)]
+pub struct Decimal64(pub(crate) BID64);
+
+impl Default for Decimal64 {
+  /// The default value of [Decimal64] is `0` (zero).
+  fn default() -> Self {
+    Self::zero()
+  }
+}
+
+impl fmt::Debug for Decimal64 {
+  /// Converts [Decimal64] into string in debug mode.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut flags = FB_CLEAR;
+    write!(f, "{}", bid64_to_string(self.0, &mut flags))
+  }
+}
+
+impl fmt::Display for Decimal64 {
+  /// Converts [Decimal64] into human readable string.
+  ///
+  /// When precision is given, the number is rounded according to the rounding
+  /// mode of the current thread's context, by default ties to even.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut flags = FB_CLEAR;
+    // widening is exact, so the digits of the wider format are written
+    match bid128_unpack(bid64_to_bid128(self.0, &mut flags)) {
+      Unpacked::Finite {
+        negative,
+        coefficient,
+        exponent,
+      } => format::Plain::new(
+        negative,
+        coefficient,
+        exponent,
+        f.precision(),
+        context::get_context().rounding(),
+      )
+      .fmt(f),
+      _ => f.pad(&bid64_to_string(self.0, &mut flags)),
+    }
+  }
+}
+
+impl Decimal64 {
+  /// Returns a [Decimal64] with value `n * 10^(-s)`.
+  pub fn new(n: i64, s: i32) -> Self {
+    let mut flags = FB_CLEAR;
+    let x = bid64_from_int64(n, RM_NEAREST_EVEN, &mut flags);
+    Self(bid64_scalbn(x, -s, RM_NEAREST_EVEN, &mut flags))
+  }
+  /// Returns a [Decimal64] with value `0`.
+  pub fn zero() -> Self {
+    Self(bid64_from_uint32(0))
+  }
+  /// Returns `true` when this number is `+0` or `-0`.
+  pub fn is_zero(&self) -> bool {
+    bid64_is_zero(self.0)
+  }
+  /// Returns `true` when this number is a NaN.
+  pub fn is_nan(&self) -> bool {
+    bid64_is_nan(self.0)
+  }
+  /// Returns `true` when this number is positive or negative infinity.
+  pub fn is_infinite(&self) -> bool {
+    bid64_is_inf(self.0)
+  }
+  /// Returns `true` when this number is neither infinite nor NaN.
+  pub fn is_finite(&self) -> bool {
+    bid64_is_finite(self.0)
+  }
+  /// Returns `true` when this number has negative sign, including `-0` and negative NaNs.
+  pub fn is_sign_negative(&self) -> bool {
+    bid64_is_signed(self.0)
+  }
+  /// Returns a [Decimal64] with value `1`.
+  pub fn one() -> Self {
+    Self(bid64_from_uint32(1))
+  }
+  /// Returns a [Decimal64] with value `2`.
+  pub fn two() -> Self {
+    Self(bid64_from_uint32(2))
+  }
+  /// Returns a [Decimal64] with value `10`.
+  pub fn ten() -> Self {
+    Self(bid64_from_uint32(10))
+  }
+  /// Returns a [Decimal64] with value `100`.
+  pub fn one_hundred() -> Self {
+    Self(bid64_from_uint32(100))
+  }
+  /// Returns a [Decimal64] with value `1000`.
+  pub fn one_thousand() -> Self {
+    Self(bid64_from_uint32(1000))
+  }
+  /// Returns the natural logarithm of this number.
+  pub fn ln(&self) -> Decimal64 {
+    let mut flags = FB_CLEAR;
+    Self(bid64_log(self.0, RM_NEAREST_EVEN, &mut flags))
+  }
+  /// Returns `e` raised to the power of this number.
+  pub fn exp(&self) -> Decimal64 {
+    let mut flags = FB_CLEAR;
+    Self(bid64_exp(self.0, RM_NEAREST_EVEN, &mut flags))
+  }
+  /// Rounds this number to `dp` decimal places, half to even.
+  pub fn round_dp(&self, dp: i32) -> Self {
+    let mut flags = FB_CLEAR;
+    let q = bid64_scalbn(Self::one().0, -dp, RM_NEAREST_EVEN, &mut flags);
+    Self(bid64_quantize(self.0, q, RM_NEAREST_EVEN, &mut flags))
+  }
+  /// Converts this number into [Decimal32], rounding according to `rounding`.
+  ///
+  /// Returns an error holding the rounded value and the raised flags
+  /// when the conversion is inexact, overflows or underflows.
+  pub fn to_decimal32(
+    &self,
+    rounding: RoundingMode,
+  ) -> Result<Decimal32, ConversionError<Decimal32>> {
+    let mut flags = FB_CLEAR;
+    let value = Decimal32(bid64_to_bid32(self.0, rounding.bits(), &mut flags));
+    if flags == FB_CLEAR {
+      Ok(value)
+    } else {
+      Err(ConversionError::new(
+        value,
+        Flags::from_bits_truncate(flags),
+      ))
+    }
+  }
+}
+
+impl std::ops::Neg for Decimal64 {
+  type Output = Self;
+  fn neg(self) -> Self::Output {
+    Self(bid64_negate(self.0))
+  }
+}
+
+impl std::ops::Add<Self> for Decimal64 {
+  type Output = Self;
+  /// Adds two [Decimal64] values.
+  fn add(self, rhs: Self) -> Self::Output {
+    let mut flags = FB_CLEAR;
+    Self(bid64_add(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl std::ops::AddAssign<Self> for Decimal64 {
+  /// Adds a [Decimal64] value in place.
+  fn add_assign(&mut self, rhs: Self) {
+    let mut flags = FB_CLEAR;
+    self.0 = bid64_add(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+  }
+}
+
+impl std::ops::Sub<Self> for Decimal64 {
+  type Output = Self;
+  /// Subtracts two [Decimal64] values.
+  fn sub(self, rhs: Self) -> Self::Output {
+    let mut flags = FB_CLEAR;
+    Self(bid64_sub(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl std::ops::SubAssign<Self> for Decimal64 {
+  /// Subtracts a [Decimal64] value in place.
+  fn sub_assign(&mut self, rhs: Self) {
+    let mut flags = FB_CLEAR;
+    self.0 = bid64_sub(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+  }
+}
+
+impl std::ops::Mul<Self> for Decimal64 {
+  type Output = Self;
+  /// Multiplies two [Decimal64] values.
+  fn mul(self, rhs: Self) -> Self::Output {
+    let mut flags = FB_CLEAR;
+    Self(bid64_mul(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl std::ops::MulAssign<Self> for Decimal64 {
+  /// Multiplies by a [Decimal64] value in place.
+  fn mul_assign(&mut self, rhs: Self) {
+    let mut flags = FB_CLEAR;
+    self.0 = bid64_mul(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+  }
+}
+
+impl std::ops::Div<Self> for Decimal64 {
+  type Output = Self;
+  /// Divides two [Decimal64] values.
+  fn div(self, rhs: Self) -> Self::Output {
+    let mut flags = FB_CLEAR;
+    Self(bid64_div(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl std::ops::DivAssign<Self> for Decimal64 {
+  /// Divides by a [Decimal64] value in place.
+  fn div_assign(&mut self, rhs: Self) {
+    let mut flags = FB_CLEAR;
+    self.0 = bid64_div(self.0, rhs.0, RM_NEAREST_EVEN, &mut flags)
+  }
+}
+
+impl PartialEq<Self> for Decimal64 {
+  /// Returns `true` when both values are numerically equal.
+  fn eq(&self, rhs: &Self) -> bool {
+    let mut flags = FB_CLEAR;
+    bid64_quiet_equal(self.0, rhs.0, &mut flags)
+  }
+}
+
+impl Eq for Decimal64 {}
+
+impl PartialOrd<Self> for Decimal64 {
+  /// Compares two [Decimal64] values.
+  fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+    Some(self.cmp(rhs))
+  }
+  /// Returns `true` when this value is less than `rhs`.
+  fn lt(&self, rhs: &Self) -> bool {
+    let mut flags = FB_CLEAR;
+    bid64_quiet_less(self.0, rhs.0, &mut flags)
+  }
+  /// Returns `true` when this value is less than or equal to `rhs`.
+  fn le(&self, rhs: &Self) -> bool {
+    let mut flags = FB_CLEAR;
+    bid64_quiet_less_equal(self.0, rhs.0, &mut flags)
+  }
+  /// Returns `true` when this value is greater than `rhs`.
+  fn gt(&self, rhs: &Self) -> bool {
+    let mut flags = FB_CLEAR;
+    bid64_quiet_greater(self.0, rhs.0, &mut flags)
+  }
+  /// Returns `true` when this value is greater than or equal to `rhs`.
+  fn ge(&self, rhs: &Self) -> bool {
+    let mut flags = FB_CLEAR;
+    bid64_quiet_greater_equal(self.0, rhs.0, &mut flags)
+  }
+}
+
+impl Ord for Decimal64 {
+  /// Compares two [Decimal64] values.
+  fn cmp(&self, rhs: &Self) -> Ordering {
+    let mut flags = FB_CLEAR;
+    if bid64_quiet_equal(self.0, rhs.0, &mut flags) {
+      return Ordering::Equal;
+    }
+    flags = FB_CLEAR;
+    if bid64_quiet_less(self.0, rhs.0, &mut flags) {
+      return Ordering::Less;
+    }
+    Ordering::Greater
+  }
+  /// Returns the greater of two [Decimal64] values.
+  fn max(self, rhs: Self) -> Self
+  where
+    Self: Sized,
+  {
+    let mut flags = FB_CLEAR;
+    Self(bid64_maxnum(self.0, rhs.0, &mut flags))
+  }
+  /// Returns the lesser of two [Decimal64] values.
+  fn min(self, rhs: Self) -> Self
+  where
+    Self: Sized,
+  {
+    let mut flags = FB_CLEAR;
+    Self(bid64_minnum(self.0, rhs.0, &mut flags))
+  }
+}
+
+impl From<&str> for Decimal64 {
+  /// Converts [Decimal64] from string, invalid input gives NaN.
+  fn from(s: &str) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_from_string(s, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl FromStr for Decimal64 {
+  type Err = Infallible;
+  /// Converts [Decimal64] from string, invalid input gives NaN.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(s.into())
+  }
+}
+
+impl From<u8> for Decimal64 {
+  /// Converts [Decimal64] from [u8].
+  fn from(n: u8) -> Self {
+    Self(bid64_from_uint32(n as u32))
+  }
+}
+
+impl From<i8> for Decimal64 {
+  /// Converts [Decimal64] from [i8].
+  fn from(n: i8) -> Self {
+    Self(bid64_from_int32(n as i32))
+  }
+}
+
+impl From<u16> for Decimal64 {
+  /// Converts [Decimal64] from [u16].
+  fn from(n: u16) -> Self {
+    Self(bid64_from_uint32(n as u32))
+  }
+}
+
+impl From<i16> for Decimal64 {
+  /// Converts [Decimal64] from [i16].
+  fn from(n: i16) -> Self {
+    Self(bid64_from_int32(n as i32))
+  }
+}
+
+impl From<u32> for Decimal64 {
+  /// Converts [Decimal64] from [u32].
+  fn from(n: u32) -> Self {
+    Self(bid64_from_uint32(n))
+  }
+}
+
+impl From<i32> for Decimal64 {
+  /// Converts [Decimal64] from [i32].
+  fn from(n: i32) -> Self {
+    Self(bid64_from_int32(n))
+  }
+}
+
+impl From<u64> for Decimal64 {
+  /// Converts [Decimal64] from [u64].
+  fn from(n: u64) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_from_uint64(n, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl From<i64> for Decimal64 {
+  /// Converts [Decimal64] from [i64].
+  fn from(n: i64) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_from_int64(n, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl From<usize> for Decimal64 {
+  /// Converts [Decimal64] from [usize].
+  fn from(n: usize) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_from_uint64(n as u64, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl From<isize> for Decimal64 {
+  /// Converts [Decimal64] from [isize].
+  fn from(n: isize) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid64_from_int64(n as i64, RM_NEAREST_EVEN, &mut flags))
+  }
+}
+
+impl From<Decimal32> for Decimal64 {
+  /// Converts [Decimal64] from [Decimal32], the conversion is always exact.
+  fn from(n: Decimal32) -> Self {
+    let mut flags = FB_CLEAR;
+    Self(bid32_to_bid64(n.0, &mut flags))
+  }
+}
@@ -0,0 +1,169 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Common interface of all decimal floating point types.
+
+use crate::{Decimal128, Decimal32, Decimal64};
+use std::fmt;
+use std::ops::{
+  Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// Decimal floating point number of any width.
+///
+/// Implemented by [Decimal32], [Decimal64] and [Decimal128],
+/// so algorithms can be written once and used with any precision.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{Decimal128, Decimal64, DecimalFloat};
+///
+/// fn average<T: DecimalFloat>(values: &[T]) -> T {
+///   let mut sum = T::zero();
+///   for value in values {
+///     sum += *value;
+///   }
+///   sum / T::from(values.len() as u32)
+/// }
+///
+/// let values = [Decimal64::from(1_u8), Decimal64::from(2_u8)];
+/// assert_eq!("1.5", average(&values).to_string());
+/// let values = [Decimal128::from(1_u8), Decimal128::from(2_u8)];
+/// assert_eq!("1.5", average(&values).to_string());
+/// ```
+pub trait DecimalFloat:
+  Copy
+  + Default
+  + fmt::Debug
+  + fmt::Display
+  + Ord
+  + Neg<Output = Self>
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<Output = Self>
+  + Div<Output = Self>
+  + AddAssign
+  + SubAssign
+  + MulAssign
+  + DivAssign
+  + FromStr
+  + for<'a> From<&'a str>
+  + From<i32>
+  + From<u32>
+  + From<i64>
+  + From<u64>
+{
+  /// Maximum number of significant decimal digits.
+  const PRECISION: u32;
+  /// Maximum adjusted exponent of a finite number.
+  const EMAX: i32;
+  /// Minimum adjusted exponent of a normal number.
+  const EMIN: i32;
+  /// Size of the number in bytes.
+  const BYTES: usize;
+
+  /// Returns a number with value `n * 10^(-s)`.
+  fn new(n: i64, s: i32) -> Self;
+  /// Returns a number with value `0`.
+  fn zero() -> Self;
+  /// Returns a number with value `1`.
+  fn one() -> Self;
+  /// Returns a number with value `2`.
+  fn two() -> Self;
+  /// Returns a number with value `10`.
+  fn ten() -> Self;
+  /// Returns the natural logarithm of this number.
+  fn ln(&self) -> Self;
+  /// Returns `e` raised to the power of this number.
+  fn exp(&self) -> Self;
+  /// Rounds this number to `dp` decimal places.
+  fn round_dp(&self, dp: i32) -> Self;
+  /// Returns `true` when this number is `+0` or `-0`.
+  fn is_zero(&self) -> bool;
+  /// Returns `true` when this number is a NaN.
+  fn is_nan(&self) -> bool;
+  /// Returns `true` when this number is positive or negative infinity.
+  fn is_infinite(&self) -> bool;
+  /// Returns `true` when this number is neither infinite nor NaN.
+  fn is_finite(&self) -> bool;
+  /// Returns `true` when this number has negative sign.
+  fn is_sign_negative(&self) -> bool;
+}
+
+macro_rules! impl_decimal_float {
+  ($t:ty, $precision:expr, $emax:expr, $bytes:expr) => {
+    impl DecimalFloat for $t {
+      const PRECISION: u32 = $precision;
+      const EMAX: i32 = $emax;
+      const EMIN: i32 = 1 - $emax;
+      const BYTES: usize = $bytes;
+
+      fn new(n: i64, s: i32) -> Self {
+        <$t>::new(n, s)
+      }
+      fn zero() -> Self {
+        <$t>::zero()
+      }
+      fn one() -> Self {
+        <$t>::one()
+      }
+      fn two() -> Self {
+        <$t>::two()
+      }
+      fn ten() -> Self {
+        <$t>::ten()
+      }
+      fn ln(&self) -> Self {
+        <$t>::ln(self)
+      }
+      fn exp(&self) -> Self {
+        <$t>::exp(self)
+      }
+      fn round_dp(&self, dp: i32) -> Self {
+        <$t>::round_dp(self, dp)
+      }
+      fn is_zero(&self) -> bool {
+        <$t>::is_zero(self)
+      }
+      fn is_nan(&self) -> bool {
+        <$t>::is_nan(self)
+      }
+      fn is_infinite(&self) -> bool {
+        <$t>::is_infinite(self)
+      }
+      fn is_finite(&self) -> bool {
+        <$t>::is_finite(self)
+      }
+      fn is_sign_negative(&self) -> bool {
+        <$t>::is_sign_negative(self)
+      }
+    }
+  };
+}
+
+impl_decimal_float!(Decimal32, 7, 96, 4);
+impl_decimal_float!(Decimal64, 16, 384, 8);
+impl_decimal_float!(Decimal128, 34, 6144, 16);
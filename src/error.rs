@@ -0,0 +1,208 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Errors reported by decimal floating point operations.
+
+use crate::Flags;
+use std::fmt;
+
+/// Error returned by a narrowing conversion that could not be done exactly.
+///
+/// Holds the value rounded according to the requested rounding mode,
+/// together with the status flags raised by the conversion.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ConversionError<T> {
+  value: T,
+  flags: Flags,
+}
+
+impl<T: Copy> ConversionError<T> {
+  /// Creates an error holding rounded value and raised flags.
+  pub(crate) fn new(value: T, flags: Flags) -> Self {
+    Self { value, flags }
+  }
+  /// Returns the rounded value.
+  pub fn value(&self) -> T {
+    self.value
+  }
+  /// Returns the flags raised by the conversion.
+  pub fn flags(&self) -> Flags {
+    self.flags
+  }
+}
+
+impl<T> fmt::Display for ConversionError<T> {
+  /// Converts [ConversionError] into human readable string.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "narrowing conversion is not exact: {}", self.flags)
+  }
+}
+
+impl<T: fmt::Debug> std::error::Error for ConversionError<T> {}
+
+/// Error returned by checked operations, naming the IEEE 754 condition
+/// that prevented an exact and finite result.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecimalError {
+  /// Invalid operation, like `0 / 0` or `ln(-1)`, or an operation on a NaN or infinity.
+  InvalidOperation,
+  /// Division of a finite non-zero number by zero, or logarithm of zero.
+  DivisionByZero,
+  /// The result exceeds the largest finite number.
+  Overflow,
+  /// The result is below the smallest normal number and was rounded.
+  Underflow,
+  /// The result was rounded.
+  Inexact,
+}
+
+impl DecimalError {
+  /// Returns the most severe condition among raised `flags`, or `None` when no flag is raised.
+  ///
+  /// Conditions are ordered from the most severe: invalid operation,
+  /// division by zero, overflow, underflow and inexact.
+  pub fn from_flags(flags: Flags) -> Option<Self> {
+    if flags.contains(Flags::INVALID) {
+      Some(Self::InvalidOperation)
+    } else if flags.contains(Flags::DIVISION_BY_ZERO) {
+      Some(Self::DivisionByZero)
+    } else if flags.contains(Flags::OVERFLOW) {
+      Some(Self::Overflow)
+    } else if flags.contains(Flags::UNDERFLOW) {
+      Some(Self::Underflow)
+    } else if flags.contains(Flags::INEXACT) {
+      Some(Self::Inexact)
+    } else {
+      None
+    }
+  }
+}
+
+impl fmt::Display for DecimalError {
+  /// Converts [DecimalError] into human readable string.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let message = match self {
+      Self::InvalidOperation => "invalid operation",
+      Self::DivisionByZero => "division by zero",
+      Self::Overflow => "overflow",
+      Self::Underflow => "underflow",
+      Self::Inexact => "inexact result",
+    };
+    write!(f, "{}", message)
+  }
+}
+
+impl std::error::Error for DecimalError {}
+
+/// Kind of failure reported by [ParseDecimalError].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+  /// The input is empty.
+  Empty,
+  /// The input contains an unexpected character at given byte position,
+  /// or ends unexpectedly when the position equals the input length.
+  InvalidDigit(usize),
+  /// Digits are not grouped correctly, the position is the byte position
+  /// where a group of unexpected length ends.
+  InvalidGrouping(usize),
+  /// The exponent is too large for the number to be represented.
+  ExponentOverflow,
+  /// The exponent is too small for the number to be represented exactly.
+  ExponentUnderflow,
+  /// The coefficient has more significant digits than can be represented exactly.
+  TooManyDigits,
+  /// The format uses the same character as decimal and grouping separator.
+  AmbiguousSeparator,
+}
+
+/// Error returned when parsing a decimal floating point number from string fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseDecimalError {
+  kind: ParseErrorKind,
+}
+
+impl ParseDecimalError {
+  /// Creates an error of given kind.
+  pub(crate) fn new(kind: ParseErrorKind) -> Self {
+    Self { kind }
+  }
+  /// Returns the kind of failure.
+  pub fn kind(&self) -> ParseErrorKind {
+    self.kind
+  }
+}
+
+impl fmt::Display for ParseDecimalError {
+  /// Converts [ParseDecimalError] into human readable string.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.kind {
+      ParseErrorKind::Empty => {
+        write!(f, "cannot parse decimal from empty string")
+      }
+      ParseErrorKind::InvalidDigit(position) => {
+        write!(f, "invalid digit found at position {}", position)
+      }
+      ParseErrorKind::InvalidGrouping(position) => {
+        write!(f, "invalid digit grouping at position {}", position)
+      }
+      ParseErrorKind::ExponentOverflow => write!(f, "exponent is too large"),
+      ParseErrorKind::ExponentUnderflow => write!(f, "exponent is too small"),
+      ParseErrorKind::TooManyDigits => write!(f, "too many significant digits"),
+      ParseErrorKind::AmbiguousSeparator => {
+        write!(f, "decimal and grouping separators are the same")
+      }
+    }
+  }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+/// Error returned when compiling a formatting mask fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MaskError {
+  /// The mask has more than three sections separated by `;`.
+  TooManySections,
+  /// A digit placeholder at given byte position follows a completed number.
+  UnexpectedDigit(usize),
+  /// A quoted literal starting at given byte position is not terminated,
+  /// or the mask ends with an escape character.
+  UnterminatedLiteral(usize),
+}
+
+impl fmt::Display for MaskError {
+  /// Converts [MaskError] into human readable string.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::TooManySections => write!(f, "mask has more than three sections"),
+      Self::UnexpectedDigit(position) => {
+        write!(f, "unexpected digit placeholder at position {}", position)
+      }
+      Self::UnterminatedLiteral(position) => {
+        write!(f, "unterminated literal at position {}", position)
+      }
+    }
+  }
+}
+
+impl std::error::Error for MaskError {}
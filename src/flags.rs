@@ -0,0 +1,177 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Status flags raised by decimal floating point operations.
+
+use dfp_number_sys::{
+  FB_CLEAR, FB_INEXACT, FB_INVALID, FB_OVERFLOW, FB_UNDERFLOW, FB_ZERO_DIVIDE,
+};
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+/// Set of IEEE 754 status flags.
+///
+/// Flags are combined with `|` and tested with [Flags::contains]
+/// or [Flags::intersects].
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::Flags;
+///
+/// let flags = Flags::INEXACT | Flags::UNDERFLOW;
+/// assert!(flags.contains(Flags::INEXACT));
+/// assert!(!flags.intersects(Flags::INVALID | Flags::OVERFLOW));
+/// assert_eq!("underflow, inexact", flags.to_string());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Flags(u32);
+
+/// Names of the flags, in the order they are reported.
+const NAMES: [(Flags, &str); 5] = [
+  (Flags::INVALID, "invalid"),
+  (Flags::DIVISION_BY_ZERO, "division by zero"),
+  (Flags::OVERFLOW, "overflow"),
+  (Flags::UNDERFLOW, "underflow"),
+  (Flags::INEXACT, "inexact"),
+];
+
+impl Flags {
+  /// Invalid operation, e.g. `0 / 0` or any operation on a signaling NaN.
+  pub const INVALID: Flags = Flags(FB_INVALID);
+  /// Division of a finite non-zero number by zero.
+  pub const DIVISION_BY_ZERO: Flags = Flags(FB_ZERO_DIVIDE);
+  /// The rounded result exceeds the largest finite number.
+  pub const OVERFLOW: Flags = Flags(FB_OVERFLOW);
+  /// The result is tiny (below the smallest normal number) and inexact.
+  pub const UNDERFLOW: Flags = Flags(FB_UNDERFLOW);
+  /// The result was rounded.
+  pub const INEXACT: Flags = Flags(FB_INEXACT);
+
+  /// Returns an empty set of flags.
+  pub const fn empty() -> Self {
+    Self(FB_CLEAR)
+  }
+  /// Returns the set of all flags.
+  pub const fn all() -> Self {
+    Self(FB_INVALID | FB_ZERO_DIVIDE | FB_OVERFLOW | FB_UNDERFLOW | FB_INEXACT)
+  }
+  /// Returns flags from raw bits as used by the
+  /// **Intel(R) Decimal Floating-Point Math Library**, unknown bits are dropped.
+  pub const fn from_bits_truncate(bits: u32) -> Self {
+    Self(bits & Self::all().0)
+  }
+  /// Returns raw bits of the flags.
+  pub const fn bits(&self) -> u32 {
+    self.0
+  }
+  /// Returns `true` when no flag is set.
+  pub const fn is_empty(&self) -> bool {
+    self.0 == FB_CLEAR
+  }
+  /// Returns `true` when all flags in `other` are set.
+  pub const fn contains(&self, other: Flags) -> bool {
+    self.0 & other.0 == other.0
+  }
+  /// Returns `true` when any flag in `other` is set.
+  pub const fn intersects(&self, other: Flags) -> bool {
+    self.0 & other.0 != FB_CLEAR
+  }
+  /// Sets all flags in `other`.
+  pub fn insert(&mut self, other: Flags) {
+    self.0 |= other.0;
+  }
+  /// Clears all flags in `other`.
+  pub fn remove(&mut self, other: Flags) {
+    self.0 &= !other.0;
+  }
+}
+
+impl fmt::Debug for Flags {
+  /// Converts [Flags] into string in debug mode, like `Flags(OVERFLOW | INEXACT)`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Flags(")?;
+    let mut separator = "";
+    for (flag, name) in NAMES {
+      if self.contains(flag) {
+        let name = name.to_uppercase().replace(' ', "_");
+        write!(f, "{}{}", separator, name)?;
+        separator = " | ";
+      }
+    }
+    write!(f, ")")
+  }
+}
+
+impl fmt::Display for Flags {
+  /// Converts [Flags] into comma separated list of flag names, like `overflow, inexact`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut separator = "";
+    for (flag, name) in NAMES {
+      if self.contains(flag) {
+        write!(f, "{}{}", separator, name)?;
+        separator = ", ";
+      }
+    }
+    Ok(())
+  }
+}
+
+impl BitOr for Flags {
+  type Output = Self;
+  /// Returns the union of two sets of flags.
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self(self.0 | rhs.0)
+  }
+}
+
+impl BitOrAssign for Flags {
+  /// Adds flags to this set.
+  fn bitor_assign(&mut self, rhs: Self) {
+    self.0 |= rhs.0;
+  }
+}
+
+impl BitAnd for Flags {
+  type Output = Self;
+  /// Returns the intersection of two sets of flags.
+  fn bitand(self, rhs: Self) -> Self::Output {
+    Self(self.0 & rhs.0)
+  }
+}
+
+impl BitAndAssign for Flags {
+  /// Keeps only flags present in both sets.
+  fn bitand_assign(&mut self, rhs: Self) {
+    self.0 &= rhs.0;
+  }
+}
+
+impl Not for Flags {
+  type Output = Self;
+  /// Returns the complement of this set of flags.
+  fn not(self) -> Self::Output {
+    Self(!self.0 & Self::all().0)
+  }
+}
@@ -0,0 +1,538 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Formatting shared by all decimal types.
+
+use crate::bid128::Unpacked;
+use crate::RoundingMode;
+use std::fmt;
+use std::fmt::Write;
+
+/// Returns the sign, the digits of the integral part and the digits
+/// of the fractional part of a number.
+///
+/// When precision is given and the number has more significant fraction digits,
+/// it is rounded to `precision` digits after the decimal point according to
+/// `rounding`, otherwise fraction digits are written without trailing zeros.
+/// Returns `None` for non-finite values (infinities and NaNs).
+pub(crate) fn plain_parts(
+  x: Unpacked,
+  precision: Option<usize>,
+  rounding: RoundingMode,
+) -> Option<(bool, String, String)> {
+  let Unpacked::Finite {
+    negative,
+    coefficient,
+    exponent,
+  } = x
+  else {
+    return None;
+  };
+  let mut plain = Plain::new(negative, coefficient, exponent, None, rounding);
+  if precision.is_some_and(|precision| plain.fraction > precision) {
+    plain = Plain::new(negative, coefficient, exponent, precision, rounding);
+  }
+  let mut s = String::with_capacity(plain.len());
+  // writing into a string never fails
+  let _ = plain.write_unsigned(&mut s);
+  let (before, after) = s.split_once('.').unwrap_or((&s, ""));
+  Some((negative, before.to_string(), after.to_string()))
+}
+
+/// Writes the finite number `(-1)^negative * coefficient * 10^exponent`
+/// in scientific notation (like `1.23456e3`).
+///
+/// Without precision all significant digits are written, otherwise the number
+/// is rounded to given number of digits after the decimal point according to `rounding`.
+pub(crate) fn exponential(
+  negative: bool,
+  coefficient: u128,
+  exponent: i32,
+  upper: bool,
+  rounding: RoundingMode,
+  f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+  let (mut digits, mut start) = coefficient_digits(coefficient);
+  let mut end = DIGITS;
+  let mut exponent = exponent as i64 + (end - start) as i64 - 1;
+  while end > start && digits[end - 1] == b'0' {
+    end -= 1;
+  }
+  if start == end {
+    // zero is written as a single digit
+    start -= 1;
+    exponent = 0;
+  }
+  let mut zeros = 0;
+  if let Some(precision) = f.precision() {
+    let n = precision.saturating_add(1);
+    if end - start > n {
+      let first = digits[start + n];
+      let rest = digits[start + n + 1..end].iter().any(|ch| *ch != b'0');
+      end = start + n;
+      let rounded =
+        round_digits(&mut digits, start, end, first, rest, negative, rounding);
+      if rounded < start {
+        start = rounded;
+        end -= 1;
+        exponent += 1;
+      }
+    } else {
+      zeros = n - (end - start);
+    }
+  }
+  let fraction = end - start - 1 + zeros;
+  let exponent_len = exponent.unsigned_abs().checked_ilog10().unwrap_or(0)
+    as usize
+    + 1
+    + (exponent < 0) as usize;
+  let len = 1 + if fraction > 0 { fraction + 1 } else { 0 } + 1 + exponent_len;
+  pad_integral(negative, len, f, |f| {
+    // only ASCII digits are stored
+    let digits = std::str::from_utf8(&digits[start..end]).unwrap();
+    f.write_str(&digits[..1])?;
+    if fraction > 0 {
+      f.write_char('.')?;
+      f.write_str(&digits[1..])?;
+      write_zeros(f, zeros)?;
+    }
+    f.write_char(if upper { 'E' } else { 'e' })?;
+    write!(f, "{}", exponent)
+  })
+}
+
+/// Rounds the kept digits `digits[start..end]` according to `rounding`,
+/// `first` is the first dropped digit and `rest` tells if any other dropped
+/// digit is nonzero. When no digit is kept, the last kept digit is zero.
+///
+/// Returns the index of the first digit, which is one less than `start`
+/// when rounding carried into a new leading digit (like `999` to `1000`).
+fn round_digits(
+  digits: &mut [u8; DIGITS],
+  start: usize,
+  end: usize,
+  first: u8,
+  rest: bool,
+  negative: bool,
+  rounding: RoundingMode,
+) -> usize {
+  let odd = end > start && (digits[end - 1] - b'0') % 2 == 1;
+  let inexact = first != b'0' || rest;
+  let increment = match rounding {
+    RoundingMode::NearestEven => {
+      first > b'5' || (first == b'5' && (rest || odd))
+    }
+    RoundingMode::NearestAway => first >= b'5',
+    RoundingMode::Upward => inexact && !negative,
+    RoundingMode::Downward => inexact && negative,
+    RoundingMode::TowardZero => false,
+  };
+  if increment {
+    for index in (start..end).rev() {
+      if digits[index] == b'9' {
+        digits[index] = b'0';
+      } else {
+        digits[index] += 1;
+        return start;
+      }
+    }
+    digits[start - 1] = b'1';
+    return start - 1;
+  }
+  start
+}
+
+/// Returns the string representation of a number as defined by the
+/// *to-scientific-string* or, when `engineering` is `true`, the
+/// *to-engineering-string* operation of the General Decimal Arithmetic Specification.
+pub(crate) fn sci_string(x: Unpacked, engineering: bool) -> String {
+  let (negative, coefficient, exponent) = match x {
+    Unpacked::Finite {
+      negative,
+      coefficient,
+      exponent,
+    } => (negative, coefficient, exponent as i64),
+    Unpacked::Infinite { negative } => {
+      return if negative { "-Infinity" } else { "Infinity" }.to_string()
+    }
+    Unpacked::NaN {
+      negative,
+      signaling,
+      payload,
+    } => {
+      let sign = if negative { "-" } else { "" };
+      let name = if signaling { "sNaN" } else { "NaN" };
+      return if payload == 0 {
+        format!("{}{}", sign, name)
+      } else {
+        format!("{}{}{}", sign, name, payload)
+      };
+    }
+  };
+  let digits = coefficient.to_string();
+  // position of the decimal point relative to the first digit
+  let left_digits = exponent + digits.len() as i64;
+  let dot = if exponent <= 0 && left_digits > -6 {
+    left_digits
+  } else if !engineering {
+    1
+  } else if coefficient == 0 {
+    (left_digits + 1).rem_euclid(3) - 1
+  } else {
+    (left_digits - 1).rem_euclid(3) + 1
+  };
+  let mut s = String::with_capacity(digits.len() + 12);
+  if negative {
+    s.push('-');
+  }
+  if dot <= 0 {
+    s.push_str("0.");
+    s.push_str(&"0".repeat(-dot as usize));
+    s.push_str(&digits);
+  } else if dot as usize >= digits.len() {
+    s.push_str(&digits);
+    s.push_str(&"0".repeat(dot as usize - digits.len()));
+  } else {
+    s.push_str(&digits[..dot as usize]);
+    s.push('.');
+    s.push_str(&digits[dot as usize..]);
+  }
+  if left_digits != dot {
+    s.push_str(&format!("E{:+}", left_digits - dot));
+  }
+  s
+}
+
+/// Returns the sign, the name and the payload of an infinity or a NaN,
+/// the name is `Infinity`, `NaN` or `sNaN`; returns `None` for finite numbers.
+pub(crate) fn special_name(x: Unpacked) -> Option<(bool, &'static str, u128)> {
+  match x {
+    Unpacked::Finite { .. } => None,
+    Unpacked::Infinite { negative } => Some((negative, "Infinity", 0)),
+    Unpacked::NaN {
+      negative,
+      signaling,
+      payload,
+    } => Some((negative, if signaling { "sNaN" } else { "NaN" }, payload)),
+  }
+}
+
+/// Writes an infinity or a NaN as `Infinity`, `-Infinity`, `NaN` or `sNaN`,
+/// followed by the payload of a NaN when the alternate flag (`{:#}`) is given,
+/// like `sNaN123`, returns `None` for finite numbers.
+///
+/// Width, fill and alignment are respected, numbers are aligned right by default.
+pub(crate) fn special(
+  x: Unpacked,
+  f: &mut fmt::Formatter<'_>,
+) -> Option<fmt::Result> {
+  let (negative, name, payload) = special_name(x)?;
+  let mut buf = [0_u8; 48];
+  let mut len = 0;
+  let mut push = |s: &[u8]| {
+    buf[len..len + s.len()].copy_from_slice(s);
+    len += s.len();
+  };
+  if negative {
+    push(b"-");
+  } else if f.sign_plus() {
+    push(b"+");
+  }
+  push(name.as_bytes());
+  if payload != 0 && f.alternate() {
+    let (digits, start) = coefficient_digits(payload);
+    push(&digits[start..]);
+  }
+  // only ASCII characters were written
+  let s = std::str::from_utf8(&buf[..len]).unwrap();
+  Some(pad(s.len(), f, |f| f.write_str(s)))
+}
+
+/// Calls `write` to write a string of `len` characters, padded to the width
+/// of the formatter with the fill character, aligned right by default.
+///
+/// Unlike [fmt::Formatter::pad], the precision is ignored.
+fn pad(
+  len: usize,
+  f: &mut fmt::Formatter<'_>,
+  write: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+  let padding = f.width().unwrap_or(0).saturating_sub(len);
+  let (before, after) = match f.align() {
+    Some(fmt::Alignment::Left) => (0, padding),
+    Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    _ => (padding, 0),
+  };
+  let fill = f.fill();
+  for _ in 0..before {
+    f.write_char(fill)?;
+  }
+  write(f)?;
+  for _ in 0..after {
+    f.write_char(fill)?;
+  }
+  Ok(())
+}
+
+/// Number of bytes needed for the digits of a 128-bit coefficient,
+/// with one spare byte in front for a carry when rounding.
+const DIGITS: usize = 36;
+
+/// Returns decimal digits of `n` written at the end of a buffer,
+/// together with the index of the first digit. Zero has no digits.
+fn coefficient_digits(mut n: u128) -> ([u8; DIGITS], usize) {
+  let mut digits = [b'0'; DIGITS];
+  let mut start = DIGITS;
+  // split into 64-bit parts to avoid slow 128-bit divisions
+  while n > 0 {
+    let mut part = (n % 10_000_000_000_000_000_000) as u64;
+    n /= 10_000_000_000_000_000_000;
+    let last = n == 0;
+    for _ in 0..19 {
+      if last && part == 0 {
+        break;
+      }
+      start -= 1;
+      digits[start] = b'0' + (part % 10) as u8;
+      part /= 10;
+    }
+  }
+  (digits, start)
+}
+
+/// Writes `n` zeros.
+fn write_zeros(w: &mut impl fmt::Write, mut n: usize) -> fmt::Result {
+  const ZEROS: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+  while n > 0 {
+    let chunk = n.min(ZEROS.len());
+    w.write_str(&ZEROS[..chunk])?;
+    n -= chunk;
+  }
+  Ok(())
+}
+
+/// Finite number in plain notation (like `-1234.5678`),
+/// with all digits kept in a buffer on the stack.
+pub(crate) struct Plain {
+  /// Flag indicating if the number is negative.
+  negative: bool,
+  /// Significant digits, without trailing zeros unless rounding produced them.
+  digits: [u8; DIGITS],
+  /// Index of the first significant digit.
+  start: usize,
+  /// Index after the last significant digit.
+  end: usize,
+  /// Decimal position of the last significant digit, `0` for units.
+  low: i64,
+  /// Number of written fraction digits.
+  fraction: usize,
+}
+
+impl Plain {
+  /// Prepares the number `(-1)^negative * coefficient * 10^exponent` for writing,
+  /// all significant fraction digits are written unless `precision` is given,
+  /// in which case the number is rounded to `precision` fraction digits.
+  pub(crate) fn new(
+    negative: bool,
+    coefficient: u128,
+    exponent: i32,
+    precision: Option<usize>,
+    rounding: RoundingMode,
+  ) -> Self {
+    let (mut digits, mut start) = coefficient_digits(coefficient);
+    let mut end = DIGITS;
+    let mut low = exponent as i64;
+    while end > start && digits[end - 1] == b'0' {
+      end -= 1;
+      low += 1;
+    }
+    if start == end {
+      low = 0;
+    }
+    let Some(precision) = precision else {
+      let fraction = (-low).max(0) as usize;
+      return Self {
+        negative,
+        digits,
+        start,
+        end,
+        low,
+        fraction,
+      };
+    };
+    let cut = -(precision.min(i32::MAX as usize) as i64);
+    if start < end && low < cut {
+      // number of kept digits, the first dropped digit is implicit zero when negative
+      let keep = (end - start) as i64 - (cut - low);
+      let (first, rest) = if keep >= 0 {
+        let first = start + keep as usize;
+        (
+          digits[first],
+          digits[first + 1..end].iter().any(|ch| *ch != b'0'),
+        )
+      } else {
+        (b'0', true)
+      };
+      end = start + keep.max(0) as usize;
+      low = cut;
+      start =
+        round_digits(&mut digits, start, end, first, rest, negative, rounding);
+      if start == end {
+        low = 0;
+      }
+    }
+    Self {
+      negative,
+      digits,
+      start,
+      end,
+      low,
+      fraction: precision,
+    }
+  }
+  /// Returns `true` when the number is negative.
+  pub(crate) fn is_negative(&self) -> bool {
+    self.negative
+  }
+  /// Returns the decimal position of the first significant digit,
+  /// or `None` when the number is zero.
+  fn top(&self) -> Option<i64> {
+    (self.start < self.end)
+      .then(|| self.low + (self.end - self.start) as i64 - 1)
+  }
+  /// Returns the number of written characters, without the sign.
+  pub(crate) fn len(&self) -> usize {
+    let integral = match self.top() {
+      Some(top) if top >= 0 => top as usize + 1,
+      _ => 1,
+    };
+    integral
+      + if self.fraction > 0 {
+        self.fraction + 1
+      } else {
+        0
+      }
+  }
+  /// Writes the number without the sign.
+  pub(crate) fn write_unsigned(&self, w: &mut impl fmt::Write) -> fmt::Result {
+    // digits between positions `from` and `to` (inclusive, `from >= to`)
+    let slice = |from: i64, to: i64| {
+      let top = self.top().unwrap_or(0);
+      let first = self.start + (top - from) as usize;
+      let last = self.start + (top - to) as usize;
+      // only ASCII digits are stored
+      std::str::from_utf8(&self.digits[first..=last]).unwrap()
+    };
+    match self.top() {
+      Some(top) if top >= 0 => {
+        w.write_str(slice(top, self.low.max(0)))?;
+        write_zeros(w, self.low.max(0) as usize)?;
+      }
+      _ => w.write_char('0')?,
+    }
+    if self.fraction > 0 {
+      w.write_char('.')?;
+      let last = -(self.fraction as i64);
+      match self.top() {
+        Some(top) if self.low <= -1 && top >= last => {
+          let from = top.min(-1);
+          let to = self.low.max(last);
+          write_zeros(w, (-1 - from) as usize)?;
+          w.write_str(slice(from, to))?;
+          write_zeros(w, (to - last) as usize)?;
+        }
+        _ => write_zeros(w, self.fraction)?,
+      }
+    }
+    Ok(())
+  }
+  /// Writes the number like [fmt::Formatter::pad_integral],
+  /// respecting sign, width, fill, alignment and zero padding.
+  pub(crate) fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pad_integral(self.negative, self.len(), f, |f| self.write_unsigned(f))
+  }
+}
+
+/// Calls `write` to write a number of `len` characters without the sign
+/// like [fmt::Formatter::pad_integral], respecting sign, width, fill,
+/// alignment and zero padding.
+fn pad_integral(
+  negative: bool,
+  len: usize,
+  f: &mut fmt::Formatter<'_>,
+  write: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+  let sign = if negative {
+    "-"
+  } else if f.sign_plus() {
+    "+"
+  } else {
+    ""
+  };
+  let len = sign.len() + len;
+  match f.width() {
+    Some(width) if width > len && f.sign_aware_zero_pad() => {
+      f.write_str(sign)?;
+      write_zeros(f, width - len)?;
+      write(f)
+    }
+    _ => pad(len, f, |f| {
+      f.write_str(sign)?;
+      write(f)
+    }),
+  }
+}
+
+/// Writer into a byte slice, failing when the slice is full.
+pub(crate) struct SliceWriter<'a> {
+  /// Buffer written to.
+  buf: &'a mut [u8],
+  /// Number of written bytes.
+  len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+  /// Creates a writer into given buffer.
+  pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+    Self { buf, len: 0 }
+  }
+  /// Returns the number of written bytes.
+  pub(crate) fn len(&self) -> usize {
+    self.len
+  }
+}
+
+impl fmt::Write for SliceWriter<'_> {
+  /// Appends the string to the buffer.
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    let end = self.len + s.len();
+    if end > self.buf.len() {
+      return Err(fmt::Error);
+    }
+    self.buf[self.len..end].copy_from_slice(s.as_bytes());
+    self.len = end;
+    Ok(())
+  }
+}
@@ -27,6 +27,34 @@
 
 extern crate dfp_number_sys;
 
+mod accounting_format;
+mod bid128;
+mod bid32;
+mod bid64;
+mod context;
 mod decimal128;
+mod decimal32;
+mod decimal64;
+mod decimal_float;
+mod error;
+mod flags;
+mod format;
+mod mask;
+mod number_format;
+mod parse;
+mod power;
+mod rounding;
+mod words;
 
+pub use accounting_format::*;
+pub use context::*;
 pub use decimal128::*;
+pub use decimal32::*;
+pub use decimal64::*;
+pub use decimal_float::*;
+pub use error::*;
+pub use flags::*;
+pub use mask::*;
+pub use number_format::*;
+pub use rounding::*;
+pub use words::*;
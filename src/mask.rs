@@ -0,0 +1,393 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Formatting of decimal numbers with spreadsheet and COBOL style masks.
+
+use crate::bid128::bid128_unpack;
+use crate::format::plain_parts;
+use crate::{Decimal128, MaskError, RoundingMode};
+use std::str::FromStr;
+
+/// Placeholder of a single digit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Slot {
+  /// Digit written always, `0` or `9` in the mask.
+  Digit,
+  /// Digit omitted when not significant, `#` in the mask.
+  Optional,
+  /// Digit replaced by the fill character when not significant,
+  /// `Z` (space) or `*` (asterisk) in the mask.
+  Fill(char),
+}
+
+/// Part of a section of the mask.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Token {
+  /// Text written as is.
+  Literal(String),
+  /// Sign placeholder, `-` writes minus or space, `+` writes minus or plus.
+  Sign(char),
+  /// Digits of the number.
+  Number,
+}
+
+/// Mask for numbers of one sign.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+struct Section {
+  /// Parts of the section in order.
+  tokens: Vec<Token>,
+  /// Placeholders of the integral part, from left to right.
+  integer: Vec<Slot>,
+  /// Positions of grouping separators, counted in digits from the decimal point.
+  separators: Vec<usize>,
+  /// Flag indicating if the decimal point is written.
+  point: bool,
+  /// Placeholders of the fractional part, from left to right.
+  fraction: Vec<Slot>,
+}
+
+/// State of compiling the number in a section.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+  /// No digit placeholder yet.
+  Before,
+  /// Compiling the integral part.
+  Integer,
+  /// Compiling the fractional part.
+  Fraction,
+  /// The number is complete.
+  After,
+}
+
+impl Section {
+  /// Compiles a section starting at byte position `offset` of the mask.
+  fn compile(mask: &str, offset: usize) -> Result<Self, MaskError> {
+    let mut section = Section::default();
+    let mut state = State::Before;
+    // grouping separators, counted in digits from the left
+    let mut separators = vec![];
+    let mut chars = mask.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+      let position = offset + index;
+      match ch {
+        '0' | '9' | '#' | 'Z' | '*' => {
+          let slot = match ch {
+            '#' => Slot::Optional,
+            'Z' => Slot::Fill(' '),
+            '*' => Slot::Fill('*'),
+            _ => Slot::Digit,
+          };
+          match state {
+            State::Before => {
+              section.tokens.push(Token::Number);
+              section.integer.push(slot);
+              state = State::Integer;
+            }
+            State::Integer => section.integer.push(slot),
+            // fill characters are meaningful only in the integral part
+            State::Fraction if slot == Slot::Optional => {
+              section.fraction.push(slot)
+            }
+            State::Fraction => section.fraction.push(Slot::Digit),
+            State::After => return Err(MaskError::UnexpectedDigit(position)),
+          }
+        }
+        ',' if state == State::Integer => {
+          separators.push(section.integer.len())
+        }
+        '.' if state == State::Integer => {
+          section.point = true;
+          state = State::Fraction;
+        }
+        '.'
+          if state == State::Before
+            && matches!(chars.peek(), Some((_, '0' | '9' | '#'))) =>
+        {
+          section.tokens.push(Token::Number);
+          section.point = true;
+          state = State::Fraction;
+        }
+        '-' | '+' => {
+          state = end(state);
+          section.tokens.push(Token::Sign(ch));
+        }
+        '"' => {
+          state = end(state);
+          let mut literal = String::new();
+          loop {
+            match chars.next() {
+              Some((_, '"')) => break,
+              Some((_, ch)) => literal.push(ch),
+              None => return Err(MaskError::UnterminatedLiteral(position)),
+            }
+          }
+          section.literal(&literal);
+        }
+        '\\' => {
+          state = end(state);
+          let Some((_, ch)) = chars.next() else {
+            return Err(MaskError::UnterminatedLiteral(position));
+          };
+          section.literal(ch.encode_utf8(&mut [0; 4]));
+        }
+        _ => {
+          state = end(state);
+          section.literal(ch.encode_utf8(&mut [0; 4]));
+        }
+      }
+    }
+    let digits = section.integer.len();
+    section.separators = separators
+      .into_iter()
+      .filter(|left| *left > 0 && *left < digits)
+      .map(|left| digits - left)
+      .collect();
+    Ok(section)
+  }
+  /// Appends a literal text.
+  fn literal(&mut self, s: &str) {
+    if let Some(Token::Literal(literal)) = self.tokens.last_mut() {
+      literal.push_str(s);
+    } else {
+      self.tokens.push(Token::Literal(s.to_string()));
+    }
+  }
+  /// Returns `true` when the section has a sign placeholder.
+  fn has_sign(&self) -> bool {
+    self
+      .tokens
+      .iter()
+      .any(|token| matches!(token, Token::Sign(_)))
+  }
+  /// Returns the digits of the integral and the fractional part of the absolute value of `x`,
+  /// rounded to the number of fraction placeholders.
+  fn digits(&self, x: Decimal128, rounding: RoundingMode) -> (String, String) {
+    let (_, before, after) =
+      plain_parts(bid128_unpack(x.0), Some(self.fraction.len()), rounding)
+        .unwrap_or_default();
+    (before, after)
+  }
+  /// Writes the number with given digits and sign according to this section.
+  fn write(
+    &self,
+    before: &str,
+    after: &str,
+    negative: bool,
+    auto_sign: bool,
+  ) -> String {
+    let mut s = String::new();
+    if auto_sign && negative {
+      s.push('-');
+    }
+    for token in &self.tokens {
+      match token {
+        Token::Literal(literal) => s.push_str(literal),
+        Token::Sign('+') => s.push(if negative { '-' } else { '+' }),
+        Token::Sign(_) => s.push(if negative { '-' } else { ' ' }),
+        Token::Number => {
+          self.write_integer(before, &mut s);
+          if self.point {
+            s.push('.');
+          }
+          self.write_fraction(after, &mut s);
+        }
+      }
+    }
+    s
+  }
+  /// Writes the integral part with significant `digits`.
+  fn write_integer(&self, digits: &str, s: &mut String) {
+    let digits = digits.trim_start_matches('0').as_bytes();
+    let slots = self.integer.len();
+    let group = self.separators.iter().min().copied();
+    // characters written from the right
+    let mut reversed = vec![];
+    for i in 0..slots.max(digits.len()) {
+      if i > 0
+        && (self.separators.contains(&i)
+          || (i >= slots && group.is_some_and(|group| i % group == 0)))
+      {
+        // the separator is written when followed by a written digit on the left
+        match self.integer.get(slots.wrapping_sub(i + 1)) {
+          _ if i < digits.len() => reversed.push(','),
+          Some(Slot::Digit) => reversed.push(','),
+          Some(Slot::Fill(fill)) => reversed.push(*fill),
+          _ => {}
+        }
+      }
+      if i < digits.len() {
+        reversed.push(digits[digits.len() - 1 - i] as char);
+      } else {
+        match self.integer[slots - 1 - i] {
+          Slot::Digit => reversed.push('0'),
+          Slot::Fill(fill) => reversed.push(fill),
+          Slot::Optional => {}
+        }
+      }
+    }
+    s.extend(reversed.iter().rev());
+  }
+  /// Writes the fractional part with given digits, trailing zeros are omitted
+  /// at optional placeholders.
+  fn write_fraction(&self, digits: &str, s: &mut String) {
+    let digits = digits.as_bytes();
+    let digit = |i: usize| digits.get(i).copied().unwrap_or(b'0');
+    let mut len = self.fraction.len();
+    while len > 0
+      && self.fraction[len - 1] == Slot::Optional
+      && digit(len - 1) == b'0'
+    {
+      len -= 1;
+    }
+    s.extend((0..len).map(|i| digit(i) as char));
+  }
+}
+
+/// Ends the number when it is being compiled.
+fn end(state: State) -> State {
+  match state {
+    State::Before => State::Before,
+    _ => State::After,
+  }
+}
+
+/// Compiled formatting mask, like `#,##0.00;(#,##0.00)`, `000000.00` or `ZZZ,ZZ9.99-`.
+///
+/// A mask has up to three sections separated by `;`, used for positive numbers,
+/// negative numbers and zero. Negative numbers are formatted with the positive
+/// section when there is no negative section, with a leading minus sign unless
+/// the section has a sign placeholder. Zero, after rounding, is formatted with
+/// the zero section when present.
+///
+/// Characters with special meaning in a section:
+///
+/// - `0` or `9` - digit, written also when it is a leading or trailing zero,
+/// - `#` - digit, omitted when it is a leading or trailing zero,
+/// - `Z` - digit, leading zero is replaced by space,
+/// - `*` - digit, leading zero is replaced by asterisk,
+/// - `,` - grouping separator between digits of the integral part; it is written
+///   only after a written digit, otherwise replaced like a leading zero,
+///   digits beyond the placeholders are grouped like the rightmost group,
+/// - `.` - decimal point,
+/// - `-` - sign, minus for negative numbers and space otherwise,
+/// - `+` - sign, minus for negative numbers and plus otherwise,
+/// - `"text"` or `\c` - literal text or character.
+///
+/// All other characters are written as is. Numbers are rounded to the number
+/// of fraction digits according to the rounding mode, ties to even by default.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{Decimal128, Mask};
+///
+/// let mask: Mask = "#,##0.00;(#,##0.00)".parse().unwrap();
+/// assert_eq!("1,234,567.89", mask.format(Decimal128::new(123456789, 2)));
+/// assert_eq!("(0.50)", mask.format(Decimal128::new(-5, 1)));
+///
+/// let mask = Mask::compile("ZZZ,ZZ9.99-").unwrap();
+/// assert_eq!("  1,234.50-", mask.format(Decimal128::new(-12345, 1)));
+/// assert_eq!("      0.05 ", mask.format(Decimal128::new(5, 2)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mask {
+  /// Sections for positive numbers, negative numbers and zero.
+  sections: Vec<Section>,
+  /// Rounding mode applied to fraction digits.
+  rounding: RoundingMode,
+}
+
+impl Mask {
+  /// Compiles the mask.
+  pub fn compile(mask: &str) -> Result<Self, MaskError> {
+    let mut sections = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (index, ch) in mask.char_indices() {
+      match ch {
+        _ if escaped => escaped = false,
+        '\\' if !quoted => escaped = true,
+        '"' => quoted = !quoted,
+        ';' if !quoted => {
+          sections.push(Section::compile(&mask[start..index], start)?);
+          start = index + 1;
+        }
+        _ => {}
+      }
+    }
+    sections.push(Section::compile(&mask[start..], start)?);
+    if sections.len() > 3 {
+      return Err(MaskError::TooManySections);
+    }
+    Ok(Self {
+      sections,
+      rounding: RoundingMode::default(),
+    })
+  }
+  /// Returns this mask with given rounding mode.
+  pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+    self.rounding = rounding;
+    self
+  }
+  /// Returns the rounding mode.
+  pub fn rounding(&self) -> RoundingMode {
+    self.rounding
+  }
+  /// Formats a number with this mask.
+  ///
+  /// Infinities and NaNs are written like by [Display](std::fmt::Display).
+  pub fn format(&self, x: Decimal128) -> String {
+    if !x.is_finite() {
+      return x.to_string();
+    }
+    let negative = x.is_sign_negative() && !x.is_zero();
+    let section = match self.sections.get(1) {
+      Some(section) if negative => section,
+      _ => &self.sections[0],
+    };
+    let (before, after) = section.digits(x, self.rounding);
+    let zero = !before.bytes().chain(after.bytes()).any(|ch| ch != b'0');
+    if zero {
+      let section = self.sections.get(2).unwrap_or(&self.sections[0]);
+      let (before, after) = section.digits(Decimal128::zero(), self.rounding);
+      return section.write(&before, &after, false, false);
+    }
+    let auto_sign = self.sections.len() == 1 && !section.has_sign();
+    section.write(
+      &before,
+      &after,
+      negative && (self.sections.len() == 1 || section.has_sign()),
+      auto_sign,
+    )
+  }
+}
+
+impl FromStr for Mask {
+  type Err = MaskError;
+  /// Compiles the mask, same as [Mask::compile].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::compile(s)
+  }
+}
@@ -0,0 +1,382 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Locale-aware parsing and formatting of decimal numbers.
+
+use crate::bid128::bid128_unpack;
+use crate::format::plain_parts;
+use crate::{
+  parse, Decimal128, ParseDecimalError, ParseErrorKind, RoundingMode,
+};
+
+/// Positions where the sign of a number may be written.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum SignPosition {
+  /// The sign precedes the number, like in `-12.50`.
+  #[default]
+  Leading,
+  /// The sign follows the number, like in `12.50-`.
+  Trailing,
+  /// The sign either precedes or follows the number.
+  Either,
+}
+
+/// Description of how numbers are written in a locale.
+///
+/// The integral part may be split into groups of digits by the grouping separator.
+/// The rightmost group has the primary group size, all other groups have
+/// the secondary group size, except the leftmost group, which may be shorter.
+/// Both sizes are three by default, Indian style grouping like `12,34,567`
+/// uses primary size three and secondary size two.
+///
+/// When parsing, the grouping is validated and the currency symbol, when set,
+/// is optional in the input and may either precede or follow the number,
+/// separated by whitespace. When formatting, the number of fraction digits
+/// is limited and the number is rounded according to the rounding mode,
+/// the currency symbol is not written.
+///
+/// # Examples
+///
+/// ```
+/// use dfp_number::{Decimal128, NumberFormat};
+///
+/// let german = NumberFormat::new(',').with_grouping_separator('.').with_currency("€");
+/// let x = Decimal128::parse_with_format("1.234.567,89 €", &german).unwrap();
+/// assert_eq!("1234567.89", x.to_string());
+///
+/// let swiss = NumberFormat::new('.').with_grouping_separator('\'');
+/// let x = Decimal128::parse_with_format("-1'234'567.89", &swiss).unwrap();
+/// assert_eq!("-1234567.89", x.to_string());
+/// assert!(Decimal128::parse_with_format("12'34.5", &swiss).is_err());
+/// ```
+///
+/// ```
+/// use dfp_number::{Decimal128, NumberFormat};
+///
+/// let indian = NumberFormat::new('.')
+///   .with_grouping_separator(',')
+///   .with_group_sizes(3, 2)
+///   .with_min_fraction_digits(2)
+///   .with_max_fraction_digits(2);
+/// assert_eq!("12,34,567.89", indian.format(Decimal128::new(123456789, 2)));
+/// assert_eq!("1,000.00", indian.format(Decimal128::new(9999995, 4)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+  /// Character separating the integral and the fractional part.
+  decimal_separator: char,
+  /// Character separating groups of digits in the integral part.
+  grouping_separator: Option<char>,
+  /// Allowed positions of the sign.
+  sign_position: SignPosition,
+  /// Currency symbol allowed before or after the number.
+  currency: Option<String>,
+  /// Number of digits in the rightmost group.
+  primary_group: usize,
+  /// Number of digits in the remaining groups.
+  secondary_group: usize,
+  /// Minimum number of written fraction digits.
+  min_fraction_digits: usize,
+  /// Maximum number of written fraction digits.
+  max_fraction_digits: Option<usize>,
+  /// Rounding mode applied when fraction digits are limited.
+  rounding: RoundingMode,
+}
+
+impl Default for NumberFormat {
+  /// Returns the format with decimal point, no grouping, leading sign,
+  /// no currency and no limits on fraction digits.
+  fn default() -> Self {
+    Self::new('.')
+  }
+}
+
+impl NumberFormat {
+  /// Creates a format with given decimal separator, no grouping, leading sign,
+  /// no currency symbol and no limits on fraction digits.
+  pub fn new(decimal_separator: char) -> Self {
+    Self {
+      decimal_separator,
+      grouping_separator: None,
+      sign_position: SignPosition::default(),
+      currency: None,
+      primary_group: 3,
+      secondary_group: 3,
+      min_fraction_digits: 0,
+      max_fraction_digits: None,
+      rounding: RoundingMode::default(),
+    }
+  }
+  /// Returns this format with given grouping separator,
+  /// which must differ from the decimal separator for parsing.
+  pub fn with_grouping_separator(mut self, separator: char) -> Self {
+    self.grouping_separator = Some(separator);
+    self
+  }
+  /// Returns this format with given allowed sign positions.
+  pub fn with_sign_position(mut self, position: SignPosition) -> Self {
+    self.sign_position = position;
+    self
+  }
+  /// Returns this format with given currency symbol.
+  pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+    self.currency = Some(currency.into());
+    self
+  }
+  /// Returns this format with given sizes of the rightmost group
+  /// and of the remaining groups of digits.
+  ///
+  /// # Panics
+  ///
+  /// Panics when any of the sizes is zero.
+  pub fn with_group_sizes(mut self, primary: usize, secondary: usize) -> Self {
+    assert!(primary > 0 && secondary > 0, "group sizes must not be zero");
+    self.primary_group = primary;
+    self.secondary_group = secondary;
+    self
+  }
+  /// Returns this format writing at least given number of fraction digits,
+  /// padded with zeros.
+  pub fn with_min_fraction_digits(mut self, digits: usize) -> Self {
+    self.min_fraction_digits = digits;
+    self
+  }
+  /// Returns this format writing at most given number of fraction digits,
+  /// the number is rounded according to the rounding mode.
+  pub fn with_max_fraction_digits(mut self, digits: usize) -> Self {
+    self.max_fraction_digits = Some(digits);
+    self
+  }
+  /// Returns this format with given rounding mode.
+  pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+    self.rounding = rounding;
+    self
+  }
+  /// Returns the decimal separator.
+  pub fn decimal_separator(&self) -> char {
+    self.decimal_separator
+  }
+  /// Returns the grouping separator.
+  pub fn grouping_separator(&self) -> Option<char> {
+    self.grouping_separator
+  }
+  /// Returns allowed sign positions.
+  pub fn sign_position(&self) -> SignPosition {
+    self.sign_position
+  }
+  /// Returns the currency symbol.
+  pub fn currency(&self) -> Option<&str> {
+    self.currency.as_deref()
+  }
+  /// Returns sizes of the rightmost group and of the remaining groups of digits.
+  pub fn group_sizes(&self) -> (usize, usize) {
+    (self.primary_group, self.secondary_group)
+  }
+  /// Returns the minimum number of fraction digits.
+  pub fn min_fraction_digits(&self) -> usize {
+    self.min_fraction_digits
+  }
+  /// Returns the maximum number of fraction digits.
+  pub fn max_fraction_digits(&self) -> Option<usize> {
+    self.max_fraction_digits
+  }
+  /// Returns the rounding mode.
+  pub fn rounding(&self) -> RoundingMode {
+    self.rounding
+  }
+  /// Formats a number in this format.
+  ///
+  /// Negative numbers have the minus sign in front, unless only the trailing sign
+  /// is allowed. Infinities and NaNs are written like by [Display](std::fmt::Display).
+  pub fn format(&self, x: Decimal128) -> String {
+    let Some((negative, digits)) = self.format_unsigned(x) else {
+      return x.to_string();
+    };
+    match (negative, self.sign_position == SignPosition::Trailing) {
+      (false, _) => digits,
+      (true, false) => format!("-{}", digits),
+      (true, true) => format!("{}-", digits),
+    }
+  }
+  /// Returns the sign of a finite number and its absolute value formatted
+  /// in this format, rounded according to the sign, `None` when not finite.
+  pub(crate) fn format_unsigned(
+    &self,
+    x: Decimal128,
+  ) -> Option<(bool, String)> {
+    let (negative, before, mut after) =
+      plain_parts(bid128_unpack(x.0), self.max_fraction_digits, self.rounding)?;
+    if after.len() < self.min_fraction_digits {
+      after.push_str(&"0".repeat(self.min_fraction_digits - after.len()));
+    }
+    let mut s = String::with_capacity(2 * (before.len() + after.len()) + 1);
+    self.group(&before, &mut s);
+    if !after.is_empty() {
+      s.push(self.decimal_separator);
+      s.push_str(&after);
+    }
+    Some((negative, s))
+  }
+  /// Appends digits of the integral part to `s`, separated into groups.
+  fn group(&self, digits: &str, s: &mut String) {
+    let Some(separator) = self.grouping_separator else {
+      s.push_str(digits);
+      return;
+    };
+    let mut boundaries = vec![];
+    let (mut end, mut size) = (digits.len(), self.primary_group);
+    while end > size {
+      end -= size;
+      boundaries.push(end);
+      size = self.secondary_group;
+    }
+    let mut start = 0;
+    for boundary in boundaries.into_iter().rev() {
+      s.push_str(&digits[start..boundary]);
+      s.push(separator);
+      start = boundary;
+    }
+    s.push_str(&digits[start..]);
+  }
+  /// Parses a number written in this format, the result is exact.
+  ///
+  /// Fails with [ParseErrorKind::AmbiguousSeparator] for any input when
+  /// the grouping separator equals the decimal separator.
+  pub fn parse(&self, s: &str) -> Result<Decimal128, ParseDecimalError> {
+    if self.grouping_separator == Some(self.decimal_separator) {
+      return Err(ParseDecimalError::new(ParseErrorKind::AmbiguousSeparator));
+    }
+    if s.is_empty() {
+      return Err(ParseDecimalError::new(ParseErrorKind::Empty));
+    }
+    let leading = self.sign_position != SignPosition::Trailing;
+    let trailing = self.sign_position != SignPosition::Leading;
+    let mut currency = self.currency.as_deref().filter(|c| !c.is_empty());
+    let mut negative = None;
+    let (mut start, mut end) = (0, s.len());
+    // sign and currency symbol before the number, in any order
+    loop {
+      let rest = &s[start..end];
+      if let (true, None, Some(minus)) =
+        (leading, negative, sign(rest.chars().next()))
+      {
+        negative = Some(minus);
+        start += 1;
+      } else if let Some(symbol) = currency.filter(|c| rest.starts_with(*c)) {
+        currency = None;
+        start = end - rest[symbol.len()..].trim_start().len();
+      } else {
+        break;
+      }
+    }
+    // sign and currency symbol after the number, in any order
+    loop {
+      let rest = &s[start..end];
+      if let (true, None, Some(minus)) =
+        (trailing, negative, sign(rest.chars().next_back()))
+      {
+        negative = Some(minus);
+        end -= 1;
+      } else if let Some(symbol) = currency.filter(|c| rest.ends_with(*c)) {
+        currency = None;
+        end = start + rest[..rest.len() - symbol.len()].trim_end().len();
+      } else {
+        break;
+      }
+    }
+    let mut digits = String::with_capacity(end - start + 1);
+    if negative == Some(true) {
+      digits.push('-');
+    }
+    self.digits(s, start, end, &mut digits)?;
+    parse::parse_bid128(digits.as_bytes()).map(Decimal128)
+  }
+  /// Appends digits of the number between `start` and `end` to `digits`,
+  /// with `.` as the decimal point, validating the grouping.
+  fn digits(
+    &self,
+    s: &str,
+    start: usize,
+    end: usize,
+    digits: &mut String,
+  ) -> Result<(), ParseDecimalError> {
+    let invalid_grouping = |position| {
+      Err(ParseDecimalError::new(ParseErrorKind::InvalidGrouping(
+        position,
+      )))
+    };
+    let mut any_digit = false;
+    let mut fraction = false;
+    let mut grouped = false;
+    // number of digits in the current group of the integral part
+    let mut group = 0;
+    for (index, ch) in s[start..end].char_indices() {
+      let position = start + index;
+      if ch.is_ascii_digit() {
+        any_digit = true;
+        group += 1;
+        digits.push(ch);
+      } else if Some(ch) == self.grouping_separator && !fraction {
+        let valid = if grouped {
+          group == self.secondary_group
+        } else {
+          (1..=self.secondary_group).contains(&group)
+        };
+        if !valid {
+          return invalid_grouping(position);
+        }
+        grouped = true;
+        group = 0;
+      } else if ch == self.decimal_separator && !fraction {
+        if grouped && group != self.primary_group {
+          return invalid_grouping(position);
+        }
+        fraction = true;
+        digits.push('.');
+      } else if Some(ch) == self.grouping_separator {
+        return invalid_grouping(position);
+      } else {
+        return Err(ParseDecimalError::new(ParseErrorKind::InvalidDigit(
+          position,
+        )));
+      }
+    }
+    if grouped && !fraction && group != self.primary_group {
+      return invalid_grouping(end);
+    }
+    if !any_digit {
+      return Err(ParseDecimalError::new(ParseErrorKind::InvalidDigit(end)));
+    }
+    Ok(())
+  }
+}
+
+/// Returns `true` for minus sign, `false` for plus sign, `None` otherwise.
+fn sign(ch: Option<char>) -> Option<bool> {
+  match ch {
+    Some('-') => Some(true),
+    Some('+') => Some(false),
+    _ => None,
+  }
+}
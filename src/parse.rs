@@ -0,0 +1,256 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Strict parsing of decimal floating point numbers.
+
+use crate::bid128::*;
+use crate::{ParseDecimalError, ParseErrorKind};
+use dfp_number_sys::BID128;
+
+/// Maximum number of significant digits of a 128-bit coefficient.
+const PRECISION: usize = 34;
+/// Maximum number of significant digits of a 128-bit NaN payload.
+const PAYLOAD_PRECISION: usize = 33;
+/// Smallest coefficient that has [PRECISION] digits.
+const MAX_SCALABLE: u128 = 10_u128.pow(PRECISION as u32 - 1);
+
+/// Names of special values, in lower case.
+const SPECIALS: [&[u8]; 4] = [b"infinity", b"inf", b"snan", b"nan"];
+
+/// Returns an error of given kind.
+fn error(kind: ParseErrorKind) -> ParseDecimalError {
+  ParseDecimalError::new(kind)
+}
+
+/// Parses a 128-bit decimal floating point number from bytes.
+///
+/// Accepted input is an optional sign followed by either digits with an optional
+/// decimal point and an optional exponent (like `-12.5e-3`), or one of the
+/// case-insensitive names `inf`, `infinity`, `nan` or `snan`, where NaNs may be
+/// followed by a decimal payload. The number must be representable exactly,
+/// trailing zeros beyond the precision are accepted and folded into the exponent.
+pub fn parse_bid128(input: &[u8]) -> Result<BID128, ParseDecimalError> {
+  if let Some(value) = plain(input) {
+    return Ok(value);
+  }
+  if input.is_empty() {
+    return Err(error(ParseErrorKind::Empty));
+  }
+  let (negative, start) = match input[0] {
+    b'-' => (true, 1),
+    b'+' => (false, 1),
+    _ => (false, 0),
+  };
+  match input.get(start) {
+    Some(ch) if ch.is_ascii_alphabetic() => special(input, start, negative),
+    _ => finite(input, start, negative),
+  }
+}
+
+/// Parses plain input of the form `[-]digits[.digits]` with at most
+/// [PRECISION] digits in a single pass, returns `None` for any other input.
+fn plain(input: &[u8]) -> Option<BID128> {
+  let (negative, digits) = match input.split_first() {
+    Some((b'-', rest)) => (true, rest),
+    _ => (false, input),
+  };
+  if digits.is_empty() || digits.len() > PRECISION + 1 {
+    return None;
+  }
+  let mut coefficient = 0_u128;
+  let mut point = None;
+  for (position, ch) in digits.iter().enumerate() {
+    match ch {
+      b'0'..=b'9' => coefficient = coefficient * 10 + (ch - b'0') as u128,
+      b'.' if point.is_none() && position > 0 => point = Some(position),
+      _ => return None,
+    }
+  }
+  let exponent = match point {
+    Some(position) if position + 1 < digits.len() => {
+      position as i32 + 1 - digits.len() as i32
+    }
+    Some(_) => return None,
+    None if digits.len() > PRECISION => return None,
+    None => 0,
+  };
+  Some(bid128_from_parts(negative, coefficient, exponent))
+}
+
+/// Parses the name of an infinity or a NaN starting at position `start`.
+fn special(
+  input: &[u8],
+  start: usize,
+  negative: bool,
+) -> Result<BID128, ParseDecimalError> {
+  let rest = &input[start..];
+  if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity")
+  {
+    return Ok(bid128_inf(negative));
+  }
+  for (name, signaling) in [(b"snan" as &[u8], true), (b"nan", false)] {
+    if rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name)
+    {
+      let payload = payload(input, start + name.len())?;
+      return Ok(bid128_nan(negative, signaling, payload));
+    }
+  }
+  let matched = SPECIALS
+    .iter()
+    .map(|name| {
+      rest
+        .iter()
+        .zip(name.iter())
+        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+        .count()
+    })
+    .max()
+    .unwrap_or(0);
+  Err(error(ParseErrorKind::InvalidDigit(start + matched)))
+}
+
+/// Parses the decimal payload of a NaN starting at position `start`.
+fn payload(input: &[u8], start: usize) -> Result<u128, ParseDecimalError> {
+  let mut payload = 0_u128;
+  let mut digits = 0;
+  for (position, ch) in input.iter().enumerate().skip(start) {
+    if !ch.is_ascii_digit() {
+      return Err(error(ParseErrorKind::InvalidDigit(position)));
+    }
+    if payload > 0 || *ch != b'0' {
+      digits += 1;
+      if digits > PAYLOAD_PRECISION {
+        return Err(error(ParseErrorKind::TooManyDigits));
+      }
+      payload = payload * 10 + (ch - b'0') as u128;
+    }
+  }
+  Ok(payload)
+}
+
+/// Parses a finite number starting at position `start`.
+fn finite(
+  input: &[u8],
+  start: usize,
+  negative: bool,
+) -> Result<BID128, ParseDecimalError> {
+  let mut coefficient = 0_u128;
+  let mut digits = 0;
+  let mut any_digit = false;
+  let mut point = false;
+  // number of digits after the decimal point
+  let mut scale = 0_i64;
+  // number of trailing zeros that did not fit into the coefficient
+  let mut dropped = 0_i64;
+  let mut position = start;
+  while let Some(&ch) = input.get(position) {
+    match ch {
+      b'0'..=b'9' => {
+        any_digit = true;
+        if point {
+          scale += 1;
+        }
+        let digit = (ch - b'0') as u128;
+        if coefficient == 0 && digit == 0 {
+          // leading zero
+        } else if digits < PRECISION {
+          coefficient = coefficient * 10 + digit;
+          digits += 1;
+        } else if digit == 0 {
+          dropped += 1;
+        } else {
+          return Err(error(ParseErrorKind::TooManyDigits));
+        }
+      }
+      b'.' if !point => point = true,
+      b'e' | b'E' if any_digit => break,
+      _ => return Err(error(ParseErrorKind::InvalidDigit(position))),
+    }
+    position += 1;
+  }
+  if !any_digit {
+    return Err(error(ParseErrorKind::InvalidDigit(position)));
+  }
+  let exponent = if position < input.len() {
+    exponent(input, position + 1)?
+  } else {
+    0
+  };
+  let exponent = exponent.saturating_sub(scale).saturating_add(dropped);
+  let (coefficient, exponent) = fit(coefficient, exponent)?;
+  Ok(bid128_from_parts(negative, coefficient, exponent))
+}
+
+/// Parses an optionally signed exponent starting at position `start`,
+/// exponents too large to be represented saturate.
+fn exponent(input: &[u8], start: usize) -> Result<i64, ParseDecimalError> {
+  let (negative, start) = match input.get(start) {
+    Some(b'-') => (true, start + 1),
+    Some(b'+') => (false, start + 1),
+    _ => (false, start),
+  };
+  if start == input.len() {
+    return Err(error(ParseErrorKind::InvalidDigit(start)));
+  }
+  let mut exponent = 0_i64;
+  for (position, ch) in input.iter().enumerate().skip(start) {
+    if !ch.is_ascii_digit() {
+      return Err(error(ParseErrorKind::InvalidDigit(position)));
+    }
+    exponent = exponent
+      .saturating_mul(10)
+      .saturating_add((ch - b'0') as i64);
+  }
+  Ok(if negative { -exponent } else { exponent })
+}
+
+/// Adjusts the coefficient and the exponent without changing the value,
+/// so that the exponent is in the representable range.
+// `u128::is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn fit(
+  mut coefficient: u128,
+  mut exponent: i64,
+) -> Result<(u128, i32), ParseDecimalError> {
+  let min = BID128_MIN_EXPONENT as i64;
+  let max = BID128_MAX_EXPONENT as i64;
+  if coefficient == 0 {
+    return Ok((0, exponent.clamp(min, max) as i32));
+  }
+  while exponent > max && coefficient < MAX_SCALABLE {
+    coefficient *= 10;
+    exponent -= 1;
+  }
+  while exponent < min && coefficient % 10 == 0 {
+    coefficient /= 10;
+    exponent += 1;
+  }
+  if exponent < min {
+    return Err(error(ParseErrorKind::ExponentUnderflow));
+  }
+  if exponent > max {
+    return Err(error(ParseErrorKind::ExponentOverflow));
+  }
+  Ok((coefficient, exponent as i32))
+}
@@ -0,0 +1,375 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Integer powers of 128-bit decimal floating-point values.
+
+use crate::bid128::{bid128_nextdown, bid128_nextup, bid128_unpack, Unpacked};
+use dfp_number_sys::*;
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+/// Maximum number of digits of an exact power.
+const MAX_DIGITS: usize = 1000;
+
+/// Number of quotient digits, one more than the precision.
+const QUOTIENT_DIGITS: usize = 35;
+
+/// Base of the limbs of big integers.
+const BASE: u64 = 1_000_000_000;
+
+/// Returns `x` raised to the power of `n`.
+///
+/// When the coefficient of a finite `x`, stripped of trailing zeros if needed,
+/// raised to the power of `n` has at most [MAX_DIGITS] digits, it is computed
+/// exactly by repeated squaring and, for negative `n`, by long division,
+/// so the result is rounded only once.
+/// Otherwise the result of `bid128_pow` is returned.
+pub fn bid128_powi(x: BID128, n: i32, round: u32, flags: &mut u32) -> BID128 {
+  if let Unpacked::Finite {
+    negative,
+    coefficient,
+    exponent,
+  } = bid128_unpack(x)
+  {
+    if let Some(r) = exact(negative, coefficient, exponent, n, round, flags) {
+      return r;
+    }
+  }
+  let mut pow_flags = FB_CLEAR;
+  let r = bid128_pow(x, bid128_from_int32(n), round, &mut pow_flags);
+  // the library reports overflow also for results too small in magnitude
+  if pow_flags & FB_OVERFLOW != 0 && is_tiny(r) {
+    pow_flags = pow_flags & !FB_OVERFLOW | FB_UNDERFLOW;
+  }
+  *flags |= pow_flags;
+  r
+}
+
+/// Returns `true` when `x` is zero or less in magnitude
+/// than the smallest normal number.
+fn is_tiny(x: BID128) -> bool {
+  matches!(
+    bid128_unpack(x),
+    Unpacked::Finite { coefficient, exponent, .. }
+      if coefficient == 0 || coefficient.ilog10() as i32 + exponent < -6143
+  )
+}
+
+/// Compares `r` squared with the exact sum of squares of `a` and `b`,
+/// or returns `None` when any of them is not finite.
+pub fn cmp_hypot(a: BID128, b: BID128, r: BID128) -> Option<Ordering> {
+  let (mut x, mut y, r) = (Square::new(a)?, Square::new(b)?, Square::new(r)?);
+  if x.top < y.top {
+    std::mem::swap(&mut x, &mut y);
+  }
+  // the sum is not less than the greater square and less than ten times it
+  if r.top > x.top + 1 {
+    return Some(Ordering::Greater);
+  }
+  if r.top < x.top {
+    return Some(Ordering::Less);
+  }
+  if y.top <= x.exponent.min(r.exponent) {
+    // the smaller square is less than the last digit of both others,
+    // so it only breaks ties
+    return Some(match r.cmp(&x) {
+      Ordering::Equal if !y.coefficient.is_empty() => Ordering::Less,
+      ordering => ordering,
+    });
+  }
+  // all exponents are within a few hundred digits of each other here
+  let min = x.exponent.min(y.exponent).min(r.exponent);
+  let mut sum = x.scaled(min);
+  add(&mut sum, &y.scaled(min));
+  Some(compare(&r.scaled(min), &sum))
+}
+
+/// Returns the hypotenuse of finite `a` and `b` rounded upward or downward,
+/// starting from its approximation `r` a few units in the last place off.
+pub fn hypot_directed(
+  a: BID128,
+  b: BID128,
+  mut r: BID128,
+  upward: bool,
+) -> BID128 {
+  // `true` when `r` is on the requested side of the exact hypotenuse
+  let fits = |r: BID128| {
+    !bid128_is_signed(r)
+      && cmp_hypot(a, b, r).map_or(upward, |ordering| {
+        ordering
+          != if upward {
+            Ordering::Less
+          } else {
+            Ordering::Greater
+          }
+      })
+  };
+  let (toward, back): (Next, Next) = if upward {
+    (bid128_nextup, bid128_nextdown)
+  } else {
+    (bid128_nextdown, bid128_nextup)
+  };
+  let mut flags = FB_CLEAR;
+  while !fits(r) {
+    r = toward(r, &mut flags);
+  }
+  loop {
+    let next = back(r, &mut flags);
+    if !fits(next) {
+      return r;
+    }
+    r = next;
+  }
+}
+
+/// Function returning the adjacent value in one direction.
+type Next = fn(BID128, &mut u32) -> BID128;
+
+/// Square of a finite number, `coefficient * 10^exponent`.
+struct Square {
+  coefficient: Vec<u32>,
+  exponent: i64,
+  /// Exponent plus the number of digits of the coefficient, so a nonzero
+  /// square is less than `10^top` and not less than `10^(top - 1)`.
+  top: i64,
+}
+
+impl Square {
+  /// Returns the square of `x`, or `None` when `x` is not finite.
+  fn new(x: BID128) -> Option<Self> {
+    let Unpacked::Finite {
+      coefficient,
+      exponent,
+      ..
+    } = bid128_unpack(x)
+    else {
+      return None;
+    };
+    let coefficient = limbs(coefficient);
+    let coefficient = mul(&coefficient, &coefficient);
+    let exponent = 2 * exponent as i64;
+    let top = match coefficient.last() {
+      Some(limb) => {
+        exponent + 9 * (coefficient.len() as i64 - 1) + limb.ilog10() as i64 + 1
+      }
+      None => i64::MIN / 4,
+    };
+    Some(Self {
+      coefficient,
+      exponent,
+      top,
+    })
+  }
+
+  /// Returns the coefficient for exponent `min`, not greater than own exponent.
+  fn scaled(&self, min: i64) -> Vec<u32> {
+    if self.coefficient.is_empty() {
+      return vec![];
+    }
+    mul(
+      &self.coefficient,
+      &pow(vec![10], (self.exponent - min) as u32),
+    )
+  }
+
+  /// Compares squares.
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.top.cmp(&other.top).then_with(|| {
+      let min = self.exponent.min(other.exponent);
+      compare(&self.scaled(min), &other.scaled(min))
+    })
+  }
+}
+
+/// Returns the power of a finite nonzero number rounded once,
+/// or `None` when the exact power is too long.
+// `u128::is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn exact(
+  negative: bool,
+  mut coefficient: u128,
+  mut exponent: i32,
+  n: i32,
+  round: u32,
+  flags: &mut u32,
+) -> Option<BID128> {
+  if coefficient == 0 || n == 0 {
+    return None;
+  }
+  let m = n.unsigned_abs();
+  // powers of one have a single digit
+  let fits = |coefficient: u128| {
+    coefficient == 1
+      || (coefficient.ilog10() as usize + 1)
+        .checked_mul(m as usize)
+        .is_some_and(|digits| digits <= MAX_DIGITS)
+  };
+  if !fits(coefficient) {
+    while coefficient % 10 == 0 {
+      coefficient /= 10;
+      exponent += 1;
+    }
+    if !fits(coefficient) {
+      return None;
+    }
+  }
+  let power = pow(limbs(coefficient), m);
+  let exponent = exponent as i64 * m as i64;
+  let mut s = String::with_capacity(MAX_DIGITS + 16);
+  s.push(if negative && m % 2 == 1 { '-' } else { '+' });
+  let _ = if n > 0 {
+    write_limbs(&power, &mut s);
+    write!(s, "E{}", exponent)
+  } else {
+    let (digits, digits_exponent) = reciprocal(&power);
+    s.push_str(&digits);
+    write!(s, "E{}", digits_exponent - exponent)
+  };
+  Some(bid128_from_string(&s, round, flags))
+}
+
+/// Returns significant digits and exponent of `1 / divisor`.
+///
+/// When the quotient has more than [QUOTIENT_DIGITS] digits, it is truncated
+/// and followed by digit `1`, so it rounds like the exact quotient.
+fn reciprocal(divisor: &[u32]) -> (String, i64) {
+  let mut digits = String::with_capacity(QUOTIENT_DIGITS + 1);
+  let mut remainder = vec![1];
+  let mut exponent = 0;
+  loop {
+    let mut digit = b'0';
+    while !less(&remainder, divisor) {
+      sub(&mut remainder, divisor);
+      digit += 1;
+    }
+    if digit > b'0' || !digits.is_empty() {
+      digits.push(digit as char);
+    }
+    if remainder.is_empty() || digits.len() == QUOTIENT_DIGITS {
+      break;
+    }
+    remainder = mul(&remainder, &[10]);
+    exponent -= 1;
+  }
+  if !remainder.is_empty() {
+    digits.push('1');
+    exponent -= 1;
+  }
+  (digits, exponent)
+}
+
+/// Writes digits of a nonzero big integer.
+fn write_limbs(limbs: &[u32], s: &mut String) {
+  for (i, limb) in limbs.iter().rev().enumerate() {
+    let _ = if i == 0 {
+      write!(s, "{}", limb)
+    } else {
+      write!(s, "{:09}", limb)
+    };
+  }
+}
+
+/// Returns limbs of `value`, the least significant first.
+fn limbs(mut value: u128) -> Vec<u32> {
+  let mut limbs = vec![];
+  while value > 0 {
+    limbs.push((value % BASE as u128) as u32);
+    value /= BASE as u128;
+  }
+  limbs
+}
+
+/// Returns the product of big integers `a` and `b`.
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+  let mut product = vec![0_u64; a.len() + b.len()];
+  for (i, x) in a.iter().enumerate() {
+    let mut carry = 0;
+    for (j, y) in b.iter().enumerate() {
+      let t = product[i + j] + *x as u64 * *y as u64 + carry;
+      product[i + j] = t % BASE;
+      carry = t / BASE;
+    }
+    product[i + b.len()] = carry;
+  }
+  while product.last() == Some(&0) {
+    product.pop();
+  }
+  product.into_iter().map(|limb| limb as u32).collect()
+}
+
+/// Compares big integers `a` and `b`.
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+  a.len()
+    .cmp(&b.len())
+    .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Returns `true` when big integer `a` is less than `b`.
+fn less(a: &[u32], b: &[u32]) -> bool {
+  compare(a, b) == Ordering::Less
+}
+
+/// Adds big integer `b` to `a`.
+fn add(a: &mut Vec<u32>, b: &[u32]) {
+  if a.len() < b.len() {
+    a.resize(b.len(), 0);
+  }
+  let mut carry = 0;
+  for (i, limb) in a.iter_mut().enumerate() {
+    let t = *limb as u64 + b.get(i).copied().unwrap_or(0) as u64 + carry;
+    *limb = (t % BASE) as u32;
+    carry = t / BASE;
+  }
+  if carry > 0 {
+    a.push(carry as u32);
+  }
+}
+
+/// Subtracts big integer `b` from not less `a`.
+fn sub(a: &mut Vec<u32>, b: &[u32]) {
+  let mut borrow = 0;
+  for (i, limb) in a.iter_mut().enumerate() {
+    let t = *limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+    borrow = (t < 0) as i64;
+    *limb = (t + borrow * BASE as i64) as u32;
+  }
+  while a.last() == Some(&0) {
+    a.pop();
+  }
+}
+
+/// Returns big integer `base` raised to the power of `n`.
+fn pow(mut base: Vec<u32>, mut n: u32) -> Vec<u32> {
+  let mut power = vec![1];
+  loop {
+    if n & 1 == 1 {
+      power = mul(&power, &base);
+    }
+    n >>= 1;
+    if n == 0 {
+      return power;
+    }
+    base = mul(&base, &base);
+  }
+}
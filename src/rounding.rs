@@ -0,0 +1,63 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Rounding modes.
+
+use dfp_number_sys::{
+  RM_DOWNWARD, RM_NEAREST_AWAY, RM_NEAREST_EVEN, RM_TOWARD_ZERO, RM_UPWARD,
+};
+
+/// Rounding mode applied when a result can not be represented exactly.
+///
+/// The variants are the rounding-direction attributes
+/// defined for decimal formats in IEEE 754-2008.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+  /// Round to nearest, ties to even (`roundTiesToEven`), also known as banker's rounding.
+  #[default]
+  NearestEven,
+  /// Round to nearest, ties away from zero (`roundTiesToAway`),
+  /// also known as half-up or commercial rounding.
+  NearestAway,
+  /// Round toward positive infinity (`roundTowardPositive`), also known as ceiling.
+  Upward,
+  /// Round toward negative infinity (`roundTowardNegative`), also known as floor.
+  Downward,
+  /// Round toward zero (`roundTowardZero`), also known as truncation.
+  TowardZero,
+}
+
+impl RoundingMode {
+  /// Returns the rounding mode as used by the
+  /// **Intel(R) Decimal Floating-Point Math Library**.
+  pub(crate) fn bits(self) -> u32 {
+    match self {
+      Self::NearestEven => RM_NEAREST_EVEN,
+      Self::NearestAway => RM_NEAREST_AWAY,
+      Self::Upward => RM_UPWARD,
+      Self::Downward => RM_DOWNWARD,
+      Self::TowardZero => RM_TOWARD_ZERO,
+    }
+  }
+}
@@ -0,0 +1,537 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Spelling of amounts in words.
+
+use crate::bid128::{bid128_unpack, Unpacked};
+use crate::{Decimal128, DecimalError, RoundingMode};
+
+/// Language used to spell numbers in words.
+///
+/// Implement this trait to spell amounts in further languages.
+pub trait Language {
+  /// Returns the cardinal number `n` in words, like `one thousand two hundred thirty-four`,
+  /// or `None` when the number is too large to be spelled.
+  fn cardinal(&self, n: u128) -> Option<String>;
+  /// Returns the cardinal number `n` in words as written before the name of a unit,
+  /// for languages where it differs from the plain cardinal number,
+  /// like German `ein Euro` compared to `eins`.
+  fn cardinal_before_unit(&self, n: u128) -> Option<String> {
+    self.cardinal(n)
+  }
+  /// Returns the word joining the major and the minor part of an amount, like `and`.
+  fn conjunction(&self) -> &str;
+  /// Returns the word preceding negative amounts, like `minus`.
+  fn minus(&self) -> &str;
+}
+
+/// English language, using the short scale (`billion` is `10^9`).
+///
+/// Spells numbers below `10^36`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct English;
+
+/// English names of numbers below twenty.
+const EN_UNITS: [&str; 20] = [
+  "zero",
+  "one",
+  "two",
+  "three",
+  "four",
+  "five",
+  "six",
+  "seven",
+  "eight",
+  "nine",
+  "ten",
+  "eleven",
+  "twelve",
+  "thirteen",
+  "fourteen",
+  "fifteen",
+  "sixteen",
+  "seventeen",
+  "eighteen",
+  "nineteen",
+];
+
+/// English names of tens.
+const EN_TENS: [&str; 10] = [
+  "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty",
+  "ninety",
+];
+
+/// English names of powers of thousand.
+const EN_SCALES: [&str; 12] = [
+  "",
+  "thousand",
+  "million",
+  "billion",
+  "trillion",
+  "quadrillion",
+  "quintillion",
+  "sextillion",
+  "septillion",
+  "octillion",
+  "nonillion",
+  "decillion",
+];
+
+impl English {
+  /// Returns a number in range from 1 to 999 in words.
+  fn hundreds(n: u128) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut words = vec![];
+    if hundreds > 0 {
+      words.push(format!("{} hundred", EN_UNITS[hundreds]));
+    }
+    if rest >= 20 && rest % 10 > 0 {
+      words.push(format!("{}-{}", EN_TENS[rest / 10], EN_UNITS[rest % 10]));
+    } else if rest >= 20 {
+      words.push(EN_TENS[rest / 10].to_string());
+    } else if rest > 0 {
+      words.push(EN_UNITS[rest].to_string());
+    }
+    words.join(" ")
+  }
+}
+
+impl Language for English {
+  /// Returns the cardinal number in words, like `one thousand two hundred thirty-four`.
+  fn cardinal(&self, n: u128) -> Option<String> {
+    if n == 0 {
+      return Some(EN_UNITS[0].to_string());
+    }
+    let groups = thousands(n, EN_SCALES.len())?;
+    let words: Vec<String> = groups
+      .iter()
+      .enumerate()
+      .rev()
+      .filter(|(_, group)| **group > 0)
+      .map(|(scale, group)| {
+        let words = Self::hundreds(*group);
+        if scale > 0 {
+          format!("{} {}", words, EN_SCALES[scale])
+        } else {
+          words
+        }
+      })
+      .collect();
+    Some(words.join(" "))
+  }
+  /// Returns `and`.
+  fn conjunction(&self) -> &str {
+    "and"
+  }
+  /// Returns `minus`.
+  fn minus(&self) -> &str {
+    "minus"
+  }
+}
+
+/// German language, using the long scale (`Milliarde` is `10^9`).
+///
+/// Numbers below one million are written as a single word, like
+/// `eintausendzweihundertvierunddreißig`. Spells numbers below `10^36`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct German;
+
+/// German names of numbers below twenty, as written in compounds.
+const DE_UNITS: [&str; 20] = [
+  "null",
+  "ein",
+  "zwei",
+  "drei",
+  "vier",
+  "fünf",
+  "sechs",
+  "sieben",
+  "acht",
+  "neun",
+  "zehn",
+  "elf",
+  "zwölf",
+  "dreizehn",
+  "vierzehn",
+  "fünfzehn",
+  "sechzehn",
+  "siebzehn",
+  "achtzehn",
+  "neunzehn",
+];
+
+/// German names of tens.
+const DE_TENS: [&str; 10] = [
+  "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig",
+  "achtzig", "neunzig",
+];
+
+/// German names of powers of thousand from one million, in singular and plural.
+const DE_SCALES: [(&str, &str); 10] = [
+  ("Million", "Millionen"),
+  ("Milliarde", "Milliarden"),
+  ("Billion", "Billionen"),
+  ("Billiarde", "Billiarden"),
+  ("Trillion", "Trillionen"),
+  ("Trilliarde", "Trilliarden"),
+  ("Quadrillion", "Quadrillionen"),
+  ("Quadrilliarde", "Quadrilliarden"),
+  ("Quintillion", "Quintillionen"),
+  ("Quintilliarde", "Quintilliarden"),
+];
+
+impl German {
+  /// Returns a number in range from 1 to 999 in words, the final `ein`
+  /// is written as `eins` when `standalone` is `true`.
+  fn hundreds(n: u128, standalone: bool) -> String {
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut words = String::new();
+    if hundreds > 0 {
+      words.push_str(DE_UNITS[hundreds]);
+      words.push_str("hundert");
+    }
+    if rest >= 20 {
+      if rest % 10 > 0 {
+        words.push_str(DE_UNITS[rest % 10]);
+        words.push_str("und");
+      }
+      words.push_str(DE_TENS[rest / 10]);
+    } else if rest > 0 {
+      words.push_str(DE_UNITS[rest]);
+      if rest == 1 && standalone {
+        words.push('s');
+      }
+    }
+    words
+  }
+  /// Returns the number in words, with final `eins` when `standalone` is `true`.
+  fn spell(n: u128, standalone: bool) -> Option<String> {
+    if n == 0 {
+      return Some(DE_UNITS[0].to_string());
+    }
+    let groups = thousands(n, DE_SCALES.len() + 2)?;
+    let mut words = vec![];
+    for (scale, group) in groups
+      .iter()
+      .enumerate()
+      .skip(2)
+      .rev()
+      .filter(|(_, group)| **group > 0)
+    {
+      let (singular, plural) = DE_SCALES[scale - 2];
+      if *group == 1 {
+        words.push(format!("eine {}", singular));
+      } else {
+        // the scales are feminine, like `einhunderteine Millionen`
+        let feminine = if *group % 100 == 1 { "e" } else { "" };
+        words.push(format!(
+          "{}{} {}",
+          Self::hundreds(*group, false),
+          feminine,
+          plural
+        ));
+      }
+    }
+    let mut below_million = String::new();
+    if groups.len() > 1 && groups[1] > 0 {
+      below_million.push_str(&Self::hundreds(groups[1], false));
+      below_million.push_str("tausend");
+    }
+    if groups[0] > 0 {
+      below_million.push_str(&Self::hundreds(groups[0], standalone));
+    }
+    if !below_million.is_empty() {
+      words.push(below_million);
+    }
+    Some(words.join(" "))
+  }
+}
+
+impl Language for German {
+  /// Returns the cardinal number in words, like `eintausendzweihundertvierunddreißig`.
+  fn cardinal(&self, n: u128) -> Option<String> {
+    Self::spell(n, true)
+  }
+  /// Returns the cardinal number in words before a unit, like `ein` in `ein Euro`.
+  fn cardinal_before_unit(&self, n: u128) -> Option<String> {
+    Self::spell(n, false)
+  }
+  /// Returns `und`.
+  fn conjunction(&self) -> &str {
+    "und"
+  }
+  /// Returns `minus`.
+  fn minus(&self) -> &str {
+    "minus"
+  }
+}
+
+/// Returns groups of three digits of `n`, starting from the least significant group,
+/// or `None` when `n` has more than `max` groups.
+fn thousands(mut n: u128, max: usize) -> Option<Vec<u128>> {
+  let mut groups = vec![];
+  while n > 0 {
+    groups.push(n % 1000);
+    n /= 1000;
+  }
+  (groups.len() <= max).then_some(groups)
+}
+
+/// Style of writing the minor part (like cents) of an amount.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum FractionStyle {
+  /// The minor part is written as a fraction, like `and 56/100`,
+  /// also when it is zero, like `and 00/100`.
+  #[default]
+  Numeric,
+  /// The minor part is written in words, like `and fifty-six cents`,
+  /// it is omitted when zero.
+  Words,
+}
+
+/// Capitalization of amounts in words.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Capitalization {
+  /// Words are written as returned by the language, like `one thousand dollars`.
+  #[default]
+  AsIs,
+  /// The first letter is upper case, like `One thousand dollars`.
+  Sentence,
+  /// The first letter of every word and of every part of a hyphenated word
+  /// is upper case, like `One Thousand Twenty-One Dollars`.
+  Title,
+  /// All letters are upper case, like `ONE THOUSAND DOLLARS`.
+  Upper,
+}
+
+/// Speller of amounts in words, as printed on cheques and legal documents.
+///
+/// # Example
+///
+/// ```
+/// use dfp_number::{AmountWords, Capitalization, Decimal128, English, FractionStyle, German};
+///
+/// let x = Decimal128::new(123456, 2);
+/// let words = AmountWords::new(English);
+/// assert_eq!("one thousand two hundred thirty-four and 56/100", words.spell(x).unwrap());
+///
+/// let words = AmountWords::new(English)
+///   .with_currency("dollar", "dollars")
+///   .with_minor_unit("cent", "cents")
+///   .with_fraction_style(FractionStyle::Words)
+///   .with_capitalization(Capitalization::Sentence);
+/// assert_eq!(
+///   "One thousand two hundred thirty-four dollars and fifty-six cents",
+///   words.spell(x).unwrap()
+/// );
+///
+/// let words = AmountWords::new(German).with_currency("Euro", "Euro");
+/// assert_eq!("eintausendzweihundertvierunddreißig Euro und 56/100", words.spell(x).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct AmountWords<L: Language> {
+  /// Language of words.
+  language: L,
+  /// Names of the major unit, in singular and plural.
+  currency: Option<(String, String)>,
+  /// Names of the minor unit, in singular and plural.
+  minor_unit: Option<(String, String)>,
+  /// Number of digits of the minor part.
+  minor_digits: u32,
+  /// Style of writing the minor part.
+  fraction_style: FractionStyle,
+  /// Capitalization of words.
+  capitalization: Capitalization,
+}
+
+impl<L: Language> AmountWords<L> {
+  /// Creates a speller in given language, without unit names, with two digits
+  /// of the minor part written as a fraction and without changing capitalization.
+  pub fn new(language: L) -> Self {
+    Self {
+      language,
+      currency: None,
+      minor_unit: None,
+      minor_digits: 2,
+      fraction_style: FractionStyle::default(),
+      capitalization: Capitalization::default(),
+    }
+  }
+  /// Returns this speller with given names of the major currency unit, like `dollar` and `dollars`.
+  pub fn with_currency(
+    mut self,
+    singular: impl Into<String>,
+    plural: impl Into<String>,
+  ) -> Self {
+    self.currency = Some((singular.into(), plural.into()));
+    self
+  }
+  /// Returns this speller with given names of the minor currency unit, like `cent` and `cents`,
+  /// used when the minor part is written in words.
+  pub fn with_minor_unit(
+    mut self,
+    singular: impl Into<String>,
+    plural: impl Into<String>,
+  ) -> Self {
+    self.minor_unit = Some((singular.into(), plural.into()));
+    self
+  }
+  /// Returns this speller with given number of digits of the minor part,
+  /// amounts are rounded to this number of digits, ties to even.
+  ///
+  /// At most 38 digits can be written in [FractionStyle::Numeric],
+  /// spelling with more digits fails with [DecimalError::Overflow].
+  pub fn with_minor_digits(mut self, digits: u32) -> Self {
+    self.minor_digits = digits;
+    self
+  }
+  /// Returns this speller with given style of writing the minor part.
+  pub fn with_fraction_style(mut self, style: FractionStyle) -> Self {
+    self.fraction_style = style;
+    self
+  }
+  /// Returns this speller with given capitalization.
+  pub fn with_capitalization(mut self, capitalization: Capitalization) -> Self {
+    self.capitalization = capitalization;
+    self
+  }
+  /// Returns the language of words.
+  pub fn language(&self) -> &L {
+    &self.language
+  }
+  /// Returns the amount in words.
+  ///
+  /// Returns [DecimalError::InvalidOperation] for infinities and NaNs, and
+  /// [DecimalError::Overflow] when the amount is too large to be spelled.
+  pub fn spell(&self, x: Decimal128) -> Result<String, DecimalError> {
+    let digits = self.minor_digits.min(i32::MAX as u32) as i32;
+    let mut unpacked = bid128_unpack(x.0);
+    if matches!(unpacked, Unpacked::Finite { exponent, .. } if exponent < -digits)
+    {
+      let rounded = x.round_dp_with(digits, RoundingMode::NearestEven);
+      unpacked = bid128_unpack(rounded.0);
+    }
+    let Unpacked::Finite {
+      negative,
+      coefficient,
+      exponent,
+    } = unpacked
+    else {
+      return Err(DecimalError::InvalidOperation);
+    };
+    let (major, minor) = split(coefficient, exponent, self.minor_digits)
+      .ok_or(DecimalError::Overflow)?;
+    let mut words = vec![];
+    if negative && (major > 0 || minor > 0) {
+      words.push(self.language.minus().to_string());
+    }
+    words.push(self.count(major, self.currency.as_ref())?);
+    match self.fraction_style {
+      FractionStyle::Numeric if self.minor_digits > 0 => {
+        let denominator = 10_u128
+          .checked_pow(self.minor_digits)
+          .ok_or(DecimalError::Overflow)?;
+        words.push(self.language.conjunction().to_string());
+        words.push(format!(
+          "{:0width$}/{}",
+          minor,
+          denominator,
+          width = self.minor_digits as usize
+        ));
+      }
+      FractionStyle::Words if minor > 0 => {
+        words.push(self.language.conjunction().to_string());
+        words.push(self.count(minor, self.minor_unit.as_ref())?);
+      }
+      _ => {}
+    }
+    Ok(capitalize(&words.join(" "), self.capitalization))
+  }
+  /// Returns `n` in words followed by the name of the unit, if any.
+  fn count(
+    &self,
+    n: u128,
+    unit: Option<&(String, String)>,
+  ) -> Result<String, DecimalError> {
+    let words = match unit {
+      Some((singular, plural)) => {
+        let name = if n == 1 { singular } else { plural };
+        self
+          .language
+          .cardinal_before_unit(n)
+          .map(|words| format!("{} {}", words, name))
+      }
+      None => self.language.cardinal(n),
+    };
+    words.ok_or(DecimalError::Overflow)
+  }
+}
+
+/// Splits the number `coefficient * 10^exponent` with at most `digits` fraction digits
+/// into the integral part and the fraction digits, returns `None` when it does not fit.
+fn split(
+  coefficient: u128,
+  exponent: i32,
+  digits: u32,
+) -> Option<(u128, u128)> {
+  if exponent >= 0 {
+    return Some((
+      coefficient.checked_mul(10_u128.checked_pow(exponent as u32)?)?,
+      0,
+    ));
+  }
+  let scale = 10_u128.checked_pow(exponent.unsigned_abs())?;
+  let minor = (coefficient % scale)
+    * 10_u128.checked_pow(digits.checked_sub(exponent.unsigned_abs())?)?;
+  Some((coefficient / scale, minor))
+}
+
+/// Returns words with given capitalization.
+fn capitalize(words: &str, capitalization: Capitalization) -> String {
+  match capitalization {
+    Capitalization::AsIs => words.to_string(),
+    Capitalization::Upper => words.to_uppercase(),
+    Capitalization::Sentence => capitalize_first(words),
+    Capitalization::Title => words
+      .split(' ')
+      .map(|word| {
+        word
+          .split('-')
+          .map(capitalize_first)
+          .collect::<Vec<_>>()
+          .join("-")
+      })
+      .collect::<Vec<_>>()
+      .join(" "),
+  }
+}
+
+/// Returns the word with the first letter in upper case.
+fn capitalize_first(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
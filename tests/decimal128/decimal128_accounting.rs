@@ -0,0 +1,145 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{
+  AccountingFormat, CurrencyPosition, Decimal128, NegativeStyle, NumberFormat,
+  RoundingMode, SignPosition,
+};
+
+fn us() -> NumberFormat {
+  NumberFormat::new('.')
+    .with_grouping_separator(',')
+    .with_min_fraction_digits(2)
+    .with_max_fraction_digits(2)
+}
+
+fn german() -> NumberFormat {
+  NumberFormat::new(',')
+    .with_grouping_separator('.')
+    .with_min_fraction_digits(2)
+    .with_max_fraction_digits(2)
+}
+
+#[test]
+fn decimal128_accounting_0001() {
+  let f =
+    AccountingFormat::new(us()).with_negative_style(NegativeStyle::Parentheses);
+  assert_eq!("1,234.00", f.format(Decimal128::from(1234)));
+  assert_eq!("(1,234.00)", f.format(Decimal128::from(-1234)));
+  assert_eq!("(0.01)", f.format(Decimal128::new(-6, 3)));
+  assert_eq!("0.00", f.format(Decimal128::new(-5, 3)));
+  assert_eq!("0.00", f.format(Decimal128::new(-4, 3)));
+  assert_eq!("0.00", f.format(Decimal128::from("-0")));
+}
+
+#[test]
+fn decimal128_accounting_0002() {
+  let f = AccountingFormat::new(us()).with_currency("$");
+  assert_eq!("$1,234.50", f.format(Decimal128::new(12345, 1)));
+  assert_eq!("-$1,234.50", f.format(Decimal128::new(-12345, 1)));
+  let f = f.with_negative_style(NegativeStyle::Parentheses);
+  assert_eq!("($1,234.50)", f.format(Decimal128::new(-12345, 1)));
+  let f =
+    AccountingFormat::new(us().with_sign_position(SignPosition::Trailing))
+      .with_currency("USD")
+      .with_currency_spacing(true);
+  assert_eq!("USD 1,234.50-", f.format(Decimal128::new(-12345, 1)));
+}
+
+#[test]
+fn decimal128_accounting_0003() {
+  let f = AccountingFormat::new(german())
+    .with_currency("€")
+    .with_currency_position(CurrencyPosition::After)
+    .with_currency_spacing(true);
+  assert_eq!("1.234.567,89 €", f.format(Decimal128::new(123456789, 2)));
+  assert_eq!("-1.234.567,89 €", f.format(Decimal128::new(-123456789, 2)));
+  let f = f.with_negative_style(NegativeStyle::Parentheses);
+  assert_eq!("(1.234.567,89 €)", f.format(Decimal128::new(-123456789, 2)));
+}
+
+#[test]
+fn decimal128_accounting_0004() {
+  let f = AccountingFormat::new(us())
+    .with_currency("$")
+    .with_negative_style(NegativeStyle::CreditDebit);
+  assert_eq!("$1,234.00 DR", f.format(Decimal128::from(1234)));
+  assert_eq!("$1,234.00 CR", f.format(Decimal128::from(-1234)));
+  assert_eq!("$0.00", f.format(Decimal128::zero()));
+  assert_eq!("Infinity", f.format(Decimal128::from("inf")));
+}
+
+#[test]
+fn decimal128_accounting_0005() {
+  let f = AccountingFormat::default();
+  assert_eq!("-1234.5", f.format(Decimal128::new(-12345, 1)));
+  assert_eq!(None, f.currency());
+  assert_eq!(NegativeStyle::Sign, f.negative_style());
+  assert_eq!(CurrencyPosition::Before, f.currency_position());
+  assert_eq!(
+    "   (1,234.00)",
+    format!(
+      "{:>13}",
+      AccountingFormat::new(us())
+        .with_negative_style(NegativeStyle::Parentheses)
+        .format(Decimal128::from(-1234))
+    )
+  );
+}
+
+#[test]
+fn decimal128_accounting_0006() {
+  let down = us().with_rounding(RoundingMode::Downward);
+  let up = us().with_rounding(RoundingMode::Upward);
+  let x = Decimal128::new(-1234, 3);
+  assert_eq!("-1.24", down.format(x));
+  assert_eq!("-1.24", AccountingFormat::new(down.clone()).format(x));
+  assert_eq!(
+    "(1.24)",
+    AccountingFormat::new(down)
+      .with_negative_style(NegativeStyle::Parentheses)
+      .format(x)
+  );
+  let x = Decimal128::new(-1231, 3);
+  assert_eq!("-1.23", up.format(x));
+  assert_eq!(
+    "(1.23)",
+    AccountingFormat::new(up.clone())
+      .with_negative_style(NegativeStyle::Parentheses)
+      .format(x)
+  );
+  assert_eq!(
+    "1.23 CR",
+    AccountingFormat::new(up)
+      .with_negative_style(NegativeStyle::CreditDebit)
+      .format(x)
+  );
+  let x = Decimal128::new(-1, 3);
+  assert_eq!(
+    "$0.00",
+    AccountingFormat::new(us().with_rounding(RoundingMode::Upward))
+      .with_currency("$")
+      .format(x)
+  );
+}
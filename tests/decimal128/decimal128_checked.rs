@@ -0,0 +1,137 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, DecimalError, Flags};
+
+#[test]
+fn decimal128_checked_0001() {
+  let x = Decimal128::from("10.25");
+  eqe("+1225E-2", x.checked_add(Decimal128::two()).unwrap());
+  eqe("+825E-2", x.checked_sub(Decimal128::two()).unwrap());
+  eqe("+2050E-2", x.checked_mul(Decimal128::two()).unwrap());
+  eqe("+5125E-3", x.checked_div(Decimal128::two()).unwrap());
+}
+
+#[test]
+fn decimal128_checked_0002() {
+  let x = Decimal128::from("9.999999999999999999999999999999999E+6144");
+  assert_eq!(Err(DecimalError::Overflow), x.checked_add(x));
+  assert_eq!(
+    Err(DecimalError::Overflow),
+    x.checked_mul(Decimal128::ten())
+  );
+  assert_eq!(Err(DecimalError::Overflow), (-x).checked_sub(x));
+}
+
+#[test]
+fn decimal128_checked_0003() {
+  let x = Decimal128::from("1E-6176");
+  assert_eq!(
+    Err(DecimalError::Underflow),
+    x.checked_div(Decimal128::two())
+  );
+}
+
+#[test]
+fn decimal128_checked_0004() {
+  assert_eq!(
+    Err(DecimalError::Inexact),
+    Decimal128::one().checked_div(Decimal128::from(3_u8))
+  );
+  assert_eq!(
+    Err(DecimalError::DivisionByZero),
+    Decimal128::one().checked_div(Decimal128::zero())
+  );
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::zero().checked_div(Decimal128::zero())
+  );
+}
+
+#[test]
+fn decimal128_checked_0005() {
+  eqe("+0E+0", Decimal128::one().checked_ln().unwrap());
+  assert_eq!(Err(DecimalError::Inexact), Decimal128::two().checked_ln());
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::from(-1_i8).checked_ln()
+  );
+  assert_eq!(
+    Err(DecimalError::DivisionByZero),
+    Decimal128::zero().checked_ln()
+  );
+}
+
+#[test]
+fn decimal128_checked_0006() {
+  eqe("+1E+0", Decimal128::zero().checked_exp().unwrap());
+  assert_eq!(Err(DecimalError::Inexact), Decimal128::one().checked_exp());
+  assert_eq!(
+    Err(DecimalError::Overflow),
+    Decimal128::from(100000_u32).checked_exp()
+  );
+}
+
+#[test]
+fn decimal128_checked_0007() {
+  let x = Decimal128::from("1.50");
+  eqe("+15E-1", x.checked_round_dp(1).unwrap());
+  eqe("+1500E-3", x.checked_round_dp(3).unwrap());
+  assert_eq!(Err(DecimalError::Inexact), x.checked_round_dp(0));
+}
+
+#[test]
+fn decimal128_checked_0008() {
+  let nan = Decimal128::from("NaN");
+  let inf = Decimal128::from("Inf");
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    nan.checked_add(Decimal128::one())
+  );
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    inf.checked_mul(Decimal128::two())
+  );
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::from("sNaN").checked_round_dp(2)
+  );
+}
+
+#[test]
+fn decimal128_checked_0009() {
+  assert_eq!(None, DecimalError::from_flags(Flags::empty()));
+  assert_eq!(
+    Some(DecimalError::Overflow),
+    DecimalError::from_flags(Flags::OVERFLOW | Flags::INEXACT)
+  );
+  assert_eq!(
+    Some(DecimalError::InvalidOperation),
+    DecimalError::from_flags(Flags::all())
+  );
+  assert_eq!("division by zero", DecimalError::DivisionByZero.to_string());
+  let error: Box<dyn std::error::Error> = Box::new(DecimalError::Inexact);
+  assert_eq!("inexact result", error.to_string());
+}
@@ -0,0 +1,53 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::Decimal128;
+use std::cmp::Ordering;
+
+#[test]
+fn decimal128_cmp_0001() {
+  let a = Decimal128::one();
+  let b = Decimal128::two();
+  eqe("+2E+0", a.max(b));
+  eqe("+2E+0", b.max(a));
+  eqe("+1E+0", a.min(b));
+  eqe("+1E+0", b.min(a));
+}
+
+#[test]
+fn decimal128_cmp_0002() {
+  let a = Decimal128::from("-1.5");
+  let b = Decimal128::from("0.25");
+  assert_eq!(Ordering::Less, a.cmp(&b));
+  assert_eq!(Some(Ordering::Greater), b.partial_cmp(&a));
+  assert_eq!(
+    Some(Ordering::Equal),
+    b.partial_cmp(&Decimal128::from("0.250"))
+  );
+  assert!(a < b);
+  assert!(a <= b);
+  assert!(b > a);
+  assert!(b >= a);
+}
@@ -0,0 +1,103 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Context, Decimal128, Flags, RoundingMode};
+
+#[test]
+fn decimal128_context_0001() {
+  let ctx = Context::default();
+  assert_eq!(RoundingMode::NearestEven, ctx.rounding());
+  assert!(ctx.flags().is_empty());
+  assert!(ctx.traps().is_empty());
+}
+
+#[test]
+fn decimal128_context_0002() {
+  let mut ctx = Context::default();
+  eqe("+3E+0", ctx.add(Decimal128::one(), Decimal128::two()));
+  eqe("-1E+0", ctx.subtract(Decimal128::one(), Decimal128::two()));
+  eqe("+2E+0", ctx.multiply(Decimal128::one(), Decimal128::two()));
+  eqe("+5E-1", ctx.divide(Decimal128::one(), Decimal128::two()));
+  assert!(ctx.flags().is_empty());
+}
+
+#[test]
+fn decimal128_context_0003() {
+  let mut ctx = Context::new(RoundingMode::Upward);
+  eqe(
+    "+3333333333333333333333333333333334E-34",
+    ctx.divide(Decimal128::one(), Decimal128::from(3_u8)),
+  );
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  ctx.clear_flags();
+  assert!(ctx.flags().is_empty());
+}
+
+#[test]
+fn decimal128_context_0004() {
+  let mut ctx = Context::default();
+  ctx.divide(Decimal128::one(), Decimal128::zero());
+  ctx.divide(Decimal128::zero(), Decimal128::zero());
+  assert_eq!(Flags::DIVISION_BY_ZERO | Flags::INVALID, ctx.flags());
+}
+
+#[test]
+fn decimal128_context_0005() {
+  let mut ctx = Context::new(RoundingMode::NearestAway);
+  let x = Decimal128::from("2.345");
+  eqe("+235E-2", ctx.quantize(x, Decimal128::from("0.01")));
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  ctx.set_rounding(RoundingMode::TowardZero);
+  eqe("+234E-2", ctx.round_dp(x, 2));
+}
+
+#[test]
+fn decimal128_context_0006() {
+  let mut ctx = Context::default();
+  ctx.ln(Decimal128::from(-1_i8));
+  assert_eq!(Flags::INVALID, ctx.flags());
+  ctx.clear_flags();
+  ctx.exp(Decimal128::from("1E+10"));
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, ctx.flags());
+}
+
+#[test]
+#[should_panic(
+  expected = "decimal operation divide(1, 0) trapped: division by zero"
+)]
+fn decimal128_context_0007() {
+  let mut ctx = Context::default().with_traps(Flags::DIVISION_BY_ZERO);
+  ctx.divide(Decimal128::one(), Decimal128::zero());
+}
+
+#[test]
+fn decimal128_context_0008() {
+  let mut ctx = Context::default().with_traps(Flags::DIVISION_BY_ZERO);
+  eqe("+5E-1", ctx.divide(Decimal128::one(), Decimal128::two()));
+  ctx.set_traps(Flags::empty());
+  assert!(ctx
+    .divide(Decimal128::one(), Decimal128::zero())
+    .is_infinite());
+}
@@ -0,0 +1,92 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, Decimal32, Decimal64, Flags, RoundingMode};
+
+#[test]
+fn decimal128_convert_0001() {
+  eqe("+123456E-2", Decimal128::from(Decimal64::new(123456, 2)));
+}
+
+#[test]
+fn decimal128_convert_0002() {
+  eqe(
+    "-9999999E+90",
+    Decimal128::from(Decimal32::from("-9.999999E96")),
+  );
+}
+
+#[test]
+fn decimal128_convert_0003() {
+  let x = Decimal128::new(123456, 2);
+  let y = x.to_decimal64(RoundingMode::NearestEven).unwrap();
+  assert_eq!("+123456E-2", format!("{:?}", y));
+}
+
+#[test]
+fn decimal128_convert_0004() {
+  let x = Decimal128::from("1.23456785");
+  let e = x.to_decimal32(RoundingMode::NearestEven).unwrap_err();
+  assert_eq!(Flags::INEXACT, e.flags());
+  assert_eq!("+1234568E-6", format!("{:?}", e.value()));
+  let e = x.to_decimal32(RoundingMode::TowardZero).unwrap_err();
+  assert_eq!("+1234567E-6", format!("{:?}", e.value()));
+  let e = x.to_decimal32(RoundingMode::Upward).unwrap_err();
+  assert_eq!("+1234568E-6", format!("{:?}", e.value()));
+}
+
+#[test]
+fn decimal128_convert_0005() {
+  let x = Decimal128::from("1E+385");
+  let e = x.to_decimal64(RoundingMode::NearestEven).unwrap_err();
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, e.flags());
+  assert!(e.value().is_infinite());
+  assert_eq!(
+    "narrowing conversion is not exact: overflow, inexact",
+    e.to_string()
+  );
+}
+
+#[test]
+fn decimal128_convert_0006() {
+  let x = Decimal128::from("1E+385");
+  let e = x.to_decimal64(RoundingMode::TowardZero).unwrap_err();
+  assert_eq!("+9999999999999999E+369", format!("{:?}", e.value()));
+}
+
+#[test]
+fn decimal128_convert_0007() {
+  let x = Decimal128::from("1.5E-398");
+  let e = x.to_decimal64(RoundingMode::NearestEven).unwrap_err();
+  assert!(e.flags().contains(Flags::UNDERFLOW | Flags::INEXACT));
+  assert_eq!("+2E-398", format!("{:?}", e.value()));
+}
+
+#[test]
+fn decimal128_convert_0008() {
+  let x = Decimal128::from("-12345.67000");
+  let y = x.to_decimal32(RoundingMode::NearestEven).unwrap();
+  assert_eq!("-1234567E-2", format!("{:?}", y));
+}
@@ -22,7 +22,7 @@
  * SOFTWARE.
  */
 
-use dfp_number::Decimal128;
+use dfp_number::{local_context, Context, Decimal128, RoundingMode};
 
 #[test]
 fn decimal128_new_0001() {
@@ -134,39 +134,144 @@ fn decimal128_new_0019() {
   );
 }
 
+#[test]
+fn decimal128_new_0020() {
+  assert_eq!("0.13", format!("{:.2}", Decimal128::new(129, 3)));
+  assert_eq!("0.12", format!("{:.2}", Decimal128::new(125, 3)));
+  assert_eq!("0.14", format!("{:.2}", Decimal128::new(135, 3)));
+  assert_eq!("0.13", format!("{:.2}", Decimal128::new(12501, 5)));
+  assert_eq!("-0.13", format!("{:.2}", Decimal128::new(-129, 3)));
+  assert_eq!("2", format!("{:.0}", Decimal128::new(25, 1)));
+  assert_eq!("0", format!("{:.0}", Decimal128::new(5, 1)));
+  assert_eq!("1", format!("{:.0}", Decimal128::new(6, 1)));
+}
+
+#[test]
+fn decimal128_new_0021() {
+  assert_eq!("10.00", format!("{:.2}", Decimal128::new(9999, 3)));
+  assert_eq!("-100.0", format!("{:.1}", Decimal128::new(-99999, 3)));
+  assert_eq!("1.00", format!("{:.2}", Decimal128::new(9999, 4)));
+  assert_eq!("  10.00", format!("{:7.2}", Decimal128::new(9999, 3)));
+  assert_eq!("1000", format!("{:.0}", Decimal128::new(9995, 1)));
+}
+
+#[test]
+fn decimal128_new_0022() {
+  let _guard = local_context(Context::new(RoundingMode::NearestAway));
+  assert_eq!("0.13", format!("{:.2}", Decimal128::new(125, 3)));
+  assert_eq!("-0.13", format!("{:.2}", Decimal128::new(-125, 3)));
+  assert_eq!("1.3e-1", format!("{:.1e}", Decimal128::new(125, 3)));
+}
+
+#[test]
+fn decimal128_new_0023() {
+  let _guard = local_context(Context::new(RoundingMode::TowardZero));
+  assert_eq!("0.12", format!("{:.2}", Decimal128::new(129, 3)));
+  assert_eq!("9.99", format!("{:.2}", Decimal128::new(9999, 3)));
+}
+
+#[test]
+fn decimal128_new_0024() {
+  {
+    let _guard = local_context(Context::new(RoundingMode::Upward));
+    assert_eq!("0.13", format!("{:.2}", Decimal128::new(1201, 4)));
+    assert_eq!("-0.12", format!("{:.2}", Decimal128::new(-1201, 4)));
+  }
+  let _guard = local_context(Context::new(RoundingMode::Downward));
+  assert_eq!("0.12", format!("{:.2}", Decimal128::new(1201, 4)));
+  assert_eq!("-0.13", format!("{:.2}", Decimal128::new(-1201, 4)));
+}
+
+#[test]
+fn decimal128_new_0025() {
+  assert_eq!("0", format!("{}", Decimal128::from("0.00")));
+  assert_eq!("0", format!("{}", Decimal128::from("0E+3")));
+  assert_eq!("-0", format!("{}", Decimal128::from("-0.00")));
+  assert_eq!("0.000", format!("{:.3}", Decimal128::from("0E+3")));
+  assert_eq!("-0.00", format!("{:.2}", Decimal128::new(-1, 3)));
+}
+
+#[test]
+fn decimal128_new_0026() {
+  let x = Decimal128::from("9.999999999999999999999999999999999E+6144");
+  let s = x.to_string();
+  assert_eq!(6145, s.len());
+  assert!(s.starts_with("9999999999999999999999999999999999000"));
+  let x = Decimal128::from("-1.234567890123456789012345678901234E-6143");
+  let s = format!("{}", x);
+  assert_eq!(Decimal128::MAX_STR_LEN, s.len());
+  assert!(s.starts_with("-0.000"));
+  assert!(s.ends_with("01234567890123456789012345678901234"));
+  assert_eq!("-0.00000", format!("{:.5}", x));
+  assert_eq!("-0.0", format!("{:.1}", Decimal128::new(-1, 6176)));
+}
+
+#[test]
+fn decimal128_new_0027() {
+  let mut buf = [0; 64];
+  let mut write = |x: Decimal128| {
+    let len = x.write_to(&mut buf);
+    String::from_utf8(buf[..len].to_vec()).unwrap()
+  };
+  assert_eq!("1.2", write(Decimal128::new(120, 2)));
+  assert_eq!("-0.0000012", write(Decimal128::new(-120, 8)));
+  assert_eq!("12000", write(Decimal128::new(120, -2)));
+  assert_eq!("0", write(Decimal128::zero()));
+  assert_eq!("-Infinity", write(Decimal128::from("-inf")));
+  assert_eq!("sNaN", write("sNaN12".parse().unwrap()));
+}
+
+#[test]
+fn decimal128_new_0028() {
+  let x = Decimal128::from("-1E-6176");
+  let mut buf = vec![0; Decimal128::MAX_STR_LEN];
+  let len = x.write_to(&mut buf);
+  assert_eq!(x.to_string().as_bytes(), &buf[..len]);
+  let mut buf = [0; 4];
+  assert_eq!(4, Decimal128::new(-12, 1).write_to(&mut buf));
+  assert_eq!(b"-1.2", &buf);
+}
+
+#[test]
+#[should_panic(expected = "buffer too small to write decimal number")]
+fn decimal128_new_0029() {
+  let mut buf = [0; 4];
+  Decimal128::new(-123, 1).write_to(&mut buf);
+}
+
 #[test]
 fn decimal128_new_0100() {
-  assert_eq!("+Inf", format!("{}", Decimal128::from("Inf")));
+  assert_eq!("Infinity", format!("{}", Decimal128::from("Inf")));
 }
 
 #[test]
 fn decimal128_new_0101() {
-  assert_eq!("+Inf", format!("{}", Decimal128::from("Infinity")));
+  assert_eq!("Infinity", format!("{}", Decimal128::from("Infinity")));
 }
 
 #[test]
 fn decimal128_new_0102() {
-  assert_eq!("+Inf", format!("{}", Decimal128::from("+Inf")));
+  assert_eq!("Infinity", format!("{}", Decimal128::from("+Inf")));
 }
 
 #[test]
 fn decimal128_new_0103() {
-  assert_eq!("-Inf", format!("{}", Decimal128::from("-Inf")));
+  assert_eq!("-Infinity", format!("{}", Decimal128::from("-Inf")));
 }
 
 #[test]
 fn decimal128_new_0104() {
-  assert_eq!("-Inf", format!("{}", Decimal128::from("-Infinity")));
+  assert_eq!("-Infinity", format!("{}", Decimal128::from("-Infinity")));
 }
 
 #[test]
 fn decimal128_new_0105() {
-  assert_eq!("+NaN", format!("{}", Decimal128::from("NaN")));
+  assert_eq!("NaN", format!("{}", Decimal128::from("NaN")));
 }
 
 #[test]
 fn decimal128_new_0106() {
-  assert_eq!("+NaN", format!("{}", Decimal128::from("+NaN")));
+  assert_eq!("NaN", format!("{}", Decimal128::from("+NaN")));
 }
 
 #[test]
@@ -186,18 +291,54 @@ fn decimal128_new_0109() {
 
 #[test]
 fn decimal128_new_0110() {
-  assert_eq!("-SNaN", format!("{}", Decimal128::from("-snan")));
+  assert_eq!("-sNaN", format!("{}", Decimal128::from("-snan")));
 }
 
 #[test]
 fn decimal128_new_0111() {
-  assert_eq!("+SNaN", format!("{}", Decimal128::from("snan")));
+  assert_eq!("sNaN", format!("{}", Decimal128::from("snan")));
 }
 
 #[test]
 fn decimal128_new_0112() {
   assert_eq!(
-    "       +SNaN        ",
+    "        sNaN        ",
     format!("{:^20}", Decimal128::from("snan"))
   );
 }
+
+#[test]
+fn decimal128_new_0113() {
+  let x = "sNaN123".parse::<Decimal128>().unwrap();
+  assert_eq!("sNaN", format!("{}", x));
+  assert_eq!("sNaN123", format!("{:#}", x));
+  assert_eq!(
+    "-NaN42",
+    format!("{:#}", "-nan42".parse::<Decimal128>().unwrap())
+  );
+  assert_eq!("NaN", format!("{:#}", Decimal128::from("nan")));
+}
+
+#[test]
+fn decimal128_new_0114() {
+  let x = Decimal128::from("inf");
+  assert_eq!("  Infinity", format!("{:10}", x));
+  assert_eq!("Infinity  ", format!("{:<10}", x));
+  assert_eq!(" -Infinity ", format!("{:^11}", -x));
+  assert_eq!("**Infinity", format!("{:*>10.2}", x));
+  assert_eq!("  Infinity", format!("{:010}", x));
+  assert_eq!("+Infinity", format!("{:+}", x));
+  assert_eq!("Infinity", format!("{:e}", x));
+}
+
+#[test]
+fn decimal128_new_0115() {
+  for s in ["NaN", "-NaN", "sNaN123", "-sNaN", "Infinity", "-Infinity"] {
+    let x = s.parse::<Decimal128>().unwrap();
+    assert_eq!(s, format!("{:#}", x));
+    assert_eq!(
+      format!("{:?}", x),
+      format!("{:?}", format!("{:#}", x).parse::<Decimal128>().unwrap())
+    );
+  }
+}
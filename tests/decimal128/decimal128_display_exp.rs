@@ -0,0 +1,68 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::Decimal128;
+
+#[test]
+fn decimal128_display_exp_0001() {
+  assert_eq!("1.2345e-7", format!("{:e}", Decimal128::new(12345, 11)));
+  assert_eq!("1.2345E-7", format!("{:E}", Decimal128::new(12345, 11)));
+  assert_eq!("-1.2345e3", format!("{:e}", Decimal128::new(-12345, 1)));
+  assert_eq!("1.2e0", format!("{:e}", Decimal128::new(120, 2)));
+  assert_eq!("5e6144", format!("{:e}", Decimal128::from("5E+6144")));
+}
+
+#[test]
+fn decimal128_display_exp_0002() {
+  assert_eq!("0e0", format!("{:e}", Decimal128::zero()));
+  assert_eq!("0e0", format!("{:e}", Decimal128::from("0.000")));
+  assert_eq!("-0e0", format!("{:e}", Decimal128::from("-0")));
+  assert_eq!("0.00e0", format!("{:.2e}", Decimal128::zero()));
+}
+
+#[test]
+fn decimal128_display_exp_0003() {
+  assert_eq!("1.23e4", format!("{:.2e}", Decimal128::new(12345, 0)));
+  assert_eq!("1.24e4", format!("{:.2e}", Decimal128::new(12350, 0)));
+  assert_eq!("1.24e4", format!("{:.2e}", Decimal128::new(12351, 0)));
+  assert_eq!("1.22e4", format!("{:.2e}", Decimal128::new(12250, 0)));
+  assert_eq!("1.00e1", format!("{:.2e}", Decimal128::new(9999, 3)));
+  assert_eq!("1e1", format!("{:.0e}", Decimal128::new(95, 1)));
+  assert_eq!("1.5000e0", format!("{:.4e}", Decimal128::new(15, 1)));
+}
+
+#[test]
+fn decimal128_display_exp_0004() {
+  assert_eq!("+1.5e0", format!("{:+e}", Decimal128::new(15, 1)));
+  assert_eq!("   1.5e0", format!("{:8e}", Decimal128::new(15, 1)));
+  assert_eq!("-001.5e0", format!("{:08e}", Decimal128::new(-15, 1)));
+  assert_eq!("1.5e0***", format!("{:*<8e}", Decimal128::new(15, 1)));
+  assert_eq!("  -1.50E-3", format!("{:10.2E}", Decimal128::new(-15, 4)));
+}
+
+#[test]
+fn decimal128_display_exp_0005() {
+  assert_eq!("Infinity", format!("{:e}", Decimal128::from("inf")));
+  assert_eq!("-NaN", format!("{:E}", Decimal128::from("-nan")));
+}
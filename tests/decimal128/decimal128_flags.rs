@@ -0,0 +1,143 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{with_context, Context, Decimal128, Flags, RoundingMode};
+
+#[test]
+fn decimal128_flags_0001() {
+  let (x, flags) = Decimal128::one().add_with_flags(Decimal128::two());
+  eqe("+3E+0", x);
+  assert!(flags.is_empty());
+}
+
+#[test]
+fn decimal128_flags_0002() {
+  let x = Decimal128::from("1E+34");
+  let (y, flags) = x.sub_with_flags(Decimal128::from("0.1"));
+  eqe("+1000000000000000000000000000000000E+1", y);
+  assert_eq!(Flags::INEXACT, flags);
+  let x = Decimal128::from("1E-6170");
+  let (y, flags) = x.mul_with_flags(Decimal128::from("1.5E-10"));
+  eqe("+0E-6176", y);
+  assert_eq!(Flags::UNDERFLOW | Flags::INEXACT, flags);
+}
+
+#[test]
+fn decimal128_flags_0003() {
+  let x = Decimal128::from("1E+6000");
+  let (y, flags) = x.mul_with_flags(x);
+  assert!(y.is_infinite());
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, flags);
+}
+
+#[test]
+fn decimal128_flags_0004() {
+  let (x, flags) = Decimal128::one().div_with_flags(Decimal128::zero());
+  assert!(x.is_infinite());
+  assert_eq!(Flags::DIVISION_BY_ZERO, flags);
+  let (x, flags) = Decimal128::zero().div_with_flags(Decimal128::zero());
+  assert!(x.is_nan());
+  assert_eq!(Flags::INVALID, flags);
+  let (x, flags) = Decimal128::one().div_with_flags(Decimal128::from(3_u8));
+  eqe("+3333333333333333333333333333333333E-34", x);
+  assert_eq!(Flags::INEXACT, flags);
+}
+
+#[test]
+fn decimal128_flags_0005() {
+  let (x, flags) = Decimal128::zero().ln_with_flags();
+  assert!(x.is_infinite());
+  assert_eq!(Flags::DIVISION_BY_ZERO, flags);
+  let (x, flags) = Decimal128::one().ln_with_flags();
+  assert!(x.is_zero());
+  assert!(flags.is_empty());
+}
+
+#[test]
+fn decimal128_flags_0006() {
+  let (x, flags) = Decimal128::zero().exp_with_flags();
+  eqe("+1E+0", x);
+  assert!(flags.is_empty());
+  let (_, flags) = Decimal128::one().exp_with_flags();
+  assert_eq!(Flags::INEXACT, flags);
+}
+
+#[test]
+fn decimal128_flags_0007() {
+  let (x, flags) = Decimal128::from("1.25").round_dp_with_flags(1);
+  eqe("+12E-1", x);
+  assert_eq!(Flags::INEXACT, flags);
+  let (x, flags) = Decimal128::from("1.25").round_dp_with_flags(3);
+  eqe("+1250E-3", x);
+  assert!(flags.is_empty());
+}
+
+#[test]
+fn decimal128_flags_0008() {
+  let (x, flags) = Decimal128::from_str_with_flags("1.5");
+  eqe("+15E-1", x);
+  assert!(flags.is_empty());
+  let (x, flags) =
+    Decimal128::from_str_with_flags("1.23456789012345678901234567890123456");
+  eqe("+1234567890123456789012345678901235E-33", x);
+  assert_eq!(Flags::INEXACT, flags);
+  let (x, flags) = Decimal128::from_str_with_flags("1\0");
+  eqe("+NaN", x);
+  assert_eq!(Flags::INVALID, flags);
+}
+
+#[test]
+fn decimal128_flags_0009() {
+  let ((x, flags), ctx) =
+    with_context(Context::new(RoundingMode::TowardZero), || {
+      Decimal128::two().div_with_flags(Decimal128::from(3_u8))
+    });
+  eqe("+6666666666666666666666666666666666E-34", x);
+  assert_eq!(Flags::INEXACT, flags);
+  assert!(ctx.flags().is_empty());
+}
+
+#[test]
+fn decimal128_flags_0010() {
+  let ((x, flags), _) = with_context(
+    Context::default().with_traps(Flags::DIVISION_BY_ZERO),
+    || Decimal128::one().div_with_flags(Decimal128::zero()),
+  );
+  assert!(x.is_infinite());
+  assert_eq!(Flags::DIVISION_BY_ZERO, flags);
+}
+
+#[test]
+fn decimal128_flags_0011() {
+  let flags = Flags::OVERFLOW | Flags::INEXACT;
+  assert_eq!("Flags(OVERFLOW | INEXACT)", format!("{:?}", flags));
+  assert_eq!("overflow, inexact", flags.to_string());
+  assert_eq!(
+    Flags::INVALID | Flags::DIVISION_BY_ZERO | Flags::UNDERFLOW,
+    !flags
+  );
+  assert_eq!(Flags::all(), flags | !flags);
+  assert_eq!(Flags::empty(), flags & !flags);
+}
@@ -0,0 +1,200 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{
+  Decimal128, NumberFormat, ParseErrorKind, RoundingMode, SignPosition,
+};
+
+fn german() -> NumberFormat {
+  NumberFormat::new(',')
+    .with_grouping_separator('.')
+    .with_currency("€")
+}
+
+fn swiss() -> NumberFormat {
+  NumberFormat::new('.')
+    .with_grouping_separator('\'')
+    .with_currency("CHF")
+    .with_sign_position(SignPosition::Either)
+}
+
+fn kind(s: &str, format: &NumberFormat) -> ParseErrorKind {
+  Decimal128::parse_with_format(s, format).unwrap_err().kind()
+}
+
+#[test]
+fn decimal128_locale_0001() {
+  let f = german();
+  let p = |s| Decimal128::parse_with_format(s, &f).unwrap();
+  eqe("+123456789E-2", p("1.234.567,89"));
+  eqe("+123456789E-2", p("1234567,89"));
+  eqe("-12345E-2", p("-123,45 €"));
+  eqe("-12345E-2", p("-€123,45"));
+  eqe("+1000E+0", p("€ 1.000"));
+  eqe("+5E-1", p("0,5"));
+}
+
+#[test]
+fn decimal128_locale_0002() {
+  let f = swiss();
+  let p = |s| Decimal128::parse_with_format(s, &f).unwrap();
+  eqe("+123456789E-2", p("1'234'567.89"));
+  eqe("-123456789E-2", p("CHF -1'234'567.89"));
+  eqe("-123456789E-2", p("1'234'567.89- CHF"));
+  eqe("+12E+0", p("+12"));
+}
+
+#[test]
+fn decimal128_locale_0003() {
+  let f = german();
+  assert_eq!(ParseErrorKind::Empty, kind("", &f));
+  assert_eq!(ParseErrorKind::InvalidGrouping(5), kind("12.34,5", &f));
+  assert_eq!(ParseErrorKind::InvalidGrouping(4), kind("1234.567", &f));
+  assert_eq!(ParseErrorKind::InvalidGrouping(0), kind(".123", &f));
+  assert_eq!(ParseErrorKind::InvalidGrouping(4), kind("1'23", &swiss()));
+  assert_eq!(ParseErrorKind::InvalidGrouping(3), kind("1,2.3", &f));
+  assert_eq!(ParseErrorKind::InvalidGrouping(3), kind("1.5", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind("1x", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(5), kind("12,50-", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(3), kind("€", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind("-", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(0), kind("$1", &f));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind("--1", &swiss()));
+}
+
+#[test]
+fn decimal128_locale_0004() {
+  let f = NumberFormat::default();
+  assert_eq!(
+    ParseErrorKind::TooManyDigits,
+    kind("1.23456789012345678901234567890123456", &f)
+  );
+  assert_eq!(
+    "invalid digit grouping at position 5",
+    Decimal128::parse_with_format("12.34,5", &german())
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+fn indian() -> NumberFormat {
+  NumberFormat::new('.')
+    .with_grouping_separator(',')
+    .with_group_sizes(3, 2)
+}
+
+#[test]
+fn decimal128_locale_0005() {
+  let x = Decimal128::new(123456789, 2);
+  let f = NumberFormat::new('.').with_grouping_separator(',');
+  assert_eq!("1,234,567.89", f.format(x));
+  let f = NumberFormat::new(',').with_grouping_separator(' ');
+  assert_eq!("1 234 567,89", f.format(x));
+  assert_eq!("12,34,567.89", indian().format(x));
+  assert_eq!("1234567.89", NumberFormat::default().format(x));
+  assert_eq!("-1.234.567,89", german().format(-x));
+  assert_eq!(
+    "1'234'567.89-",
+    swiss()
+      .with_sign_position(SignPosition::Trailing)
+      .format(-x)
+  );
+}
+
+#[test]
+fn decimal128_locale_0006() {
+  let f = indian();
+  assert_eq!("0", f.format(Decimal128::zero()));
+  assert_eq!("999", f.format(Decimal128::from(999)));
+  assert_eq!("1,000", f.format(Decimal128::from(1000)));
+  assert_eq!("99,999", f.format(Decimal128::from(99999)));
+  assert_eq!("1,00,000", f.format(Decimal128::from(100000)));
+  assert_eq!("1,00,00,000", f.format(Decimal128::from(10000000)));
+}
+
+#[test]
+fn decimal128_locale_0007() {
+  let f = NumberFormat::new('.')
+    .with_grouping_separator(',')
+    .with_min_fraction_digits(2)
+    .with_max_fraction_digits(2);
+  assert_eq!("12.00", f.format(Decimal128::from(12)));
+  assert_eq!("0.12", f.format(Decimal128::new(125, 3)));
+  assert_eq!("0.14", f.format(Decimal128::new(135, 3)));
+  assert_eq!("1,000.00", f.format(Decimal128::new(9999999, 4)));
+  let f = f.with_rounding(RoundingMode::NearestAway);
+  assert_eq!("0.13", f.format(Decimal128::new(125, 3)));
+  let f = f.with_rounding(RoundingMode::Downward);
+  assert_eq!("-0.13", f.format(Decimal128::new(-121, 3)));
+}
+
+#[test]
+fn decimal128_locale_0008() {
+  let f = NumberFormat::new('.')
+    .with_min_fraction_digits(1)
+    .with_max_fraction_digits(3);
+  assert_eq!("1.0", f.format(Decimal128::from(1)));
+  assert_eq!("0.0", f.format(Decimal128::from("0E+5")));
+  assert_eq!("1.25", f.format(Decimal128::new(125, 2)));
+  assert_eq!("1.234", f.format(Decimal128::new(12345, 4)));
+  assert_eq!("1.235", f.format(Decimal128::new(12346, 4)));
+  let f = NumberFormat::new('.').with_max_fraction_digits(0);
+  assert_eq!("2", f.format(Decimal128::new(25, 1)));
+  assert_eq!("Infinity", f.format(Decimal128::from("inf")));
+}
+
+#[test]
+fn decimal128_locale_0009() {
+  let f = indian();
+  let p = |s: &str| Decimal128::parse_with_format(s, &f);
+  eqe("+1234567E+0", p("12,34,567").unwrap());
+  eqe("+1234E+0", p("1,234").unwrap());
+  assert_eq!(
+    ParseErrorKind::InvalidGrouping(3),
+    p("123,456").unwrap_err().kind()
+  );
+  assert_eq!(
+    ParseErrorKind::InvalidGrouping(5),
+    p("1,234,567").unwrap_err().kind()
+  );
+  for x in [0_i64, 7, 1000, 123456789, -98765432101] {
+    let x = Decimal128::from(x);
+    eqe(&format!("{:?}", x), p(&f.format(x)).unwrap());
+  }
+}
+
+#[test]
+fn decimal128_locale_0010() {
+  let f = NumberFormat::new('.').with_grouping_separator('.');
+  let kind = |s: &str| Decimal128::parse_with_format(s, &f).unwrap_err().kind();
+  assert_eq!(ParseErrorKind::AmbiguousSeparator, kind("1.234"));
+  assert_eq!(ParseErrorKind::AmbiguousSeparator, kind("1"));
+  assert_eq!(
+    "decimal and grouping separators are the same",
+    Decimal128::parse_with_format("1", &f)
+      .unwrap_err()
+      .to_string()
+  );
+}
@@ -0,0 +1,119 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, DecimalError, Flags, RoundingMode};
+
+#[test]
+fn decimal128_log_0001() {
+  eqe("+2E+0", Decimal128::from(100).log10());
+  eqe("+2E+0", Decimal128::from("100.00").log10());
+  eqe("-3E+0", Decimal128::from("0.001").log10());
+  eqe("+0E+0", Decimal128::one().log10());
+  eqe("+6144E+0", Decimal128::from("1E+6144").log10());
+  eqe("-6176E+0", Decimal128::from("1E-6176").log10());
+  eqe("-Inf", Decimal128::zero().log10());
+  assert!(Decimal128::from(-10).log10().is_nan());
+}
+
+#[test]
+fn decimal128_log_0002() {
+  for k in -6176..=6144 {
+    let (y, flags) =
+      Decimal128::from(format!("1E{}", k).as_str()).log10_with_flags();
+    assert_eq!(Decimal128::from(k), y);
+    assert_eq!(Flags::empty(), flags);
+    if k <= 6111 {
+      let x = Decimal128::from(
+        format!("1000000000000000000000000000000000E{}", k).as_str(),
+      );
+      assert_eq!(Ok(Decimal128::from(k + 33)), x.checked_log10());
+    }
+  }
+}
+
+#[test]
+fn decimal128_log_0003() {
+  let x = Decimal128::from(2);
+  eqe("+3010299956639811952137388947244930E-34", x.log10());
+  eqe(
+    "+3010299956639811952137388947244931E-34",
+    x.log10_with(RoundingMode::Upward),
+  );
+  assert_eq!(Err(DecimalError::Inexact), x.checked_log10());
+  eqe("+10E+0", Decimal128::from(1024).log2());
+  eqe("-3E+0", Decimal128::from("0.125").log2());
+  eqe(
+    "+1584962500721156181453738943947817E-33",
+    Decimal128::from(3).log2(),
+  );
+  eqe(
+    "+1584962500721156181453738943947816E-33",
+    Decimal128::from(3).log2_with(RoundingMode::Downward),
+  );
+}
+
+#[test]
+fn decimal128_log_0004() {
+  eqe("+1E+3", Decimal128::from(3).exp10());
+  eqe("+1E-2", Decimal128::from(-2).exp10());
+  let (y, flags) = Decimal128::from(6145).exp10_with_flags();
+  eqe("+Inf", y);
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, flags);
+  eqe(
+    "+9999999999999999999999999999999999E+6111",
+    Decimal128::from(6145).exp10_with(RoundingMode::Downward),
+  );
+  eqe("+1024E+0", Decimal128::from(10).exp2());
+  eqe("+125E-3", Decimal128::from(-3).checked_exp2().unwrap());
+  eqe(
+    "+1414213562373095048801688724209697E-33",
+    Decimal128::from("0.5").exp2_with(RoundingMode::Downward),
+  );
+}
+
+#[test]
+fn decimal128_log_0005() {
+  let x = Decimal128::from("1E-20");
+  eqe("+9999999999999999999950000000000000E-54", x.log1p());
+  eqe("+1000000000000000000005000000000000E-53", x.expm1());
+  eqe("+0E+0", Decimal128::zero().log1p());
+  eqe("+0E+0", Decimal128::zero().expm1());
+  eqe("-Inf", Decimal128::from(-1).log1p());
+  eqe(
+    "+6931471805599453094172321214581766E-34",
+    Decimal128::one().log1p(),
+  );
+  let (y, flags) = Decimal128::from(-1).expm1_with_flags();
+  eqe("-6321205588285576784044762298385391E-34", y);
+  assert_eq!(Flags::INEXACT, flags);
+  eqe(
+    "+1718281828459045235360287471352662E-33",
+    Decimal128::one().expm1_with(RoundingMode::Downward),
+  );
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::from(-2).checked_log1p()
+  );
+}
@@ -0,0 +1,114 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{Decimal128, Mask, MaskError, RoundingMode};
+
+fn mask(s: &str) -> Mask {
+  Mask::compile(s).unwrap()
+}
+
+#[test]
+fn decimal128_mask_0001() {
+  let m = mask("#,##0.00;(#,##0.00)");
+  assert_eq!("0.00", m.format(Decimal128::zero()));
+  assert_eq!("5.00", m.format(Decimal128::from(5)));
+  assert_eq!("1,234.50", m.format(Decimal128::new(12345, 1)));
+  assert_eq!("1,234,567.89", m.format(Decimal128::new(123456789, 2)));
+  assert_eq!("(1,234,567.89)", m.format(Decimal128::new(-123456789, 2)));
+  assert_eq!("(0.01)", m.format(Decimal128::new(-6, 3)));
+  assert_eq!("0.00", m.format(Decimal128::new(-4, 3)));
+}
+
+#[test]
+fn decimal128_mask_0002() {
+  let m = mask("000000.00");
+  assert_eq!("000000.00", m.format(Decimal128::zero()));
+  assert_eq!("001234.50", m.format(Decimal128::new(12345, 1)));
+  assert_eq!("-000012.35", m.format(Decimal128::new(-123456, 4)));
+  assert_eq!("1234567.00", m.format(Decimal128::from(1234567)));
+}
+
+#[test]
+fn decimal128_mask_0003() {
+  let m = mask("ZZZ,ZZ9.99-");
+  assert_eq!("      0.00 ", m.format(Decimal128::zero()));
+  assert_eq!("     12.30 ", m.format(Decimal128::new(123, 1)));
+  assert_eq!("  1,234.50-", m.format(Decimal128::new(-12345, 1)));
+  assert_eq!("123,456.00 ", m.format(Decimal128::from(123456)));
+  let m = mask("***,**9.99+");
+  assert_eq!("*****12.30+", m.format(Decimal128::new(123, 1)));
+  assert_eq!("**1,234.50-", m.format(Decimal128::new(-12345, 1)));
+}
+
+#[test]
+fn decimal128_mask_0004() {
+  let m = mask("#,##0.00;-#,##0.00;\"zero\"");
+  assert_eq!("zero", m.format(Decimal128::zero()));
+  assert_eq!("zero", m.format(Decimal128::new(-1, 3)));
+  assert_eq!("-12.00", m.format(Decimal128::from(-12)));
+  let m = mask("$#,##0.00 \\C\\R;$#,##0.00 \"DR\"");
+  assert_eq!("$12.00 CR", m.format(Decimal128::from(12)));
+  assert_eq!("$12.00 DR", m.format(Decimal128::from(-12)));
+}
+
+#[test]
+fn decimal128_mask_0005() {
+  let m = mask("#.##");
+  assert_eq!("1.5", m.format(Decimal128::new(15, 1)));
+  assert_eq!(".5", m.format(Decimal128::new(5, 1)));
+  assert_eq!("2.", m.format(Decimal128::new(2, 0)));
+  let m = mask("0.0#");
+  assert_eq!("1.25", m.format(Decimal128::new(125, 2)));
+  assert_eq!("1.2", m.format(Decimal128::new(12, 1)));
+  assert_eq!("1.0", m.format(Decimal128::from(1)));
+}
+
+#[test]
+fn decimal128_mask_0006() {
+  let m = mask("0.0");
+  assert_eq!("0.2", m.format(Decimal128::new(25, 2)));
+  let m = m.with_rounding(RoundingMode::NearestAway);
+  assert_eq!(RoundingMode::NearestAway, m.rounding());
+  assert_eq!("0.3", m.format(Decimal128::new(25, 2)));
+  assert_eq!("Infinity", m.format(Decimal128::from("inf")));
+  assert_eq!("NaN", m.format(Decimal128::from("nan")));
+}
+
+#[test]
+fn decimal128_mask_0007() {
+  assert_eq!(Err(MaskError::TooManySections), Mask::compile("0;0;0;0"));
+  assert_eq!(Err(MaskError::UnexpectedDigit(4)), Mask::compile("00 -0"));
+  assert_eq!(
+    Err(MaskError::UnterminatedLiteral(3)),
+    Mask::compile("0;0\"x")
+  );
+  assert_eq!(
+    Err(MaskError::UnterminatedLiteral(1)),
+    "0\\".parse::<Mask>()
+  );
+  assert_eq!(
+    "unexpected digit placeholder at position 4",
+    MaskError::UnexpectedDigit(4).to_string()
+  );
+}
@@ -0,0 +1,160 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, ParseDecimalError, ParseErrorKind};
+
+fn parse(s: &str) -> Result<Decimal128, ParseDecimalError> {
+  s.parse::<Decimal128>()
+}
+
+fn kind(s: &str) -> ParseErrorKind {
+  parse(s).unwrap_err().kind()
+}
+
+#[test]
+fn decimal128_parse_0001() {
+  eqe("+12345E-2", parse("123.45").unwrap());
+  eqe("-12345E-2", parse("-123.45").unwrap());
+  eqe("+5E-1", parse("+.5").unwrap());
+  eqe("+5E+0", parse("5.").unwrap());
+  eqe("+125E+1", parse("12.5e2").unwrap());
+  eqe("+125E-4", parse("12.5E-3").unwrap());
+  eqe("+0E-2", parse("0.00").unwrap());
+  eqe("-0E+0", parse("-0").unwrap());
+  eqe("+7E+0", parse("0007").unwrap());
+}
+
+#[test]
+fn decimal128_parse_0002() {
+  eqe("+Inf", parse("Inf").unwrap());
+  eqe("-Inf", parse("-infinity").unwrap());
+  assert!(parse("NaN").unwrap().is_nan());
+  assert!(parse("-sNaN").unwrap().is_nan());
+  assert!(parse("nan123").unwrap().is_nan());
+}
+
+#[test]
+fn decimal128_parse_0003() {
+  assert_eq!(ParseErrorKind::Empty, kind(""));
+  assert_eq!(ParseErrorKind::InvalidDigit(2), kind("12abc"));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind("-"));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind("."));
+  assert_eq!(ParseErrorKind::InvalidDigit(0), kind(" 1"));
+  assert_eq!(ParseErrorKind::InvalidDigit(3), kind("1.2.3"));
+  assert_eq!(ParseErrorKind::InvalidDigit(2), kind("1e"));
+  assert_eq!(ParseErrorKind::InvalidDigit(0), kind("e5"));
+  assert_eq!(ParseErrorKind::InvalidDigit(3), kind("infx"));
+  assert_eq!(ParseErrorKind::InvalidDigit(4), kind("-nanx"));
+}
+
+#[test]
+fn decimal128_parse_0004() {
+  assert_eq!(ParseErrorKind::ExponentOverflow, kind("1E+6145"));
+  assert_eq!(ParseErrorKind::ExponentUnderflow, kind("1E-6177"));
+  assert_eq!(ParseErrorKind::ExponentUnderflow, kind("-0.1E-6176"));
+  assert_eq!(
+    ParseErrorKind::ExponentOverflow,
+    kind("1E99999999999999999999")
+  );
+  assert_eq!(
+    ParseErrorKind::ExponentUnderflow,
+    kind("1E-99999999999999999999")
+  );
+  eqe(
+    "+1000000000000000000000000000000000E+6111",
+    parse("1E+6144").unwrap(),
+  );
+  eqe("+1E-6176", parse("10E-6177").unwrap());
+  eqe("+0E+6111", parse("0E+9999").unwrap());
+}
+
+#[test]
+fn decimal128_parse_0005() {
+  assert_eq!(
+    ParseErrorKind::TooManyDigits,
+    kind("1.23456789012345678901234567890123456")
+  );
+  eqe(
+    "+1234567890123456789012345678901234E+2",
+    parse("123456789012345678901234567890123400").unwrap(),
+  );
+}
+
+#[test]
+fn decimal128_parse_0006() {
+  assert_eq!(
+    "invalid digit found at position 2",
+    parse("12abc").unwrap_err().to_string()
+  );
+  assert_eq!(
+    "cannot parse decimal from empty string",
+    parse("").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn decimal128_parse_0007() {
+  assert!(Decimal128::from_str_lossy("12abc").is_nan());
+  eqe("+NaN", Decimal128::from_str_lossy("1\0"));
+  eqe("+NaN", Decimal128::from("\0"));
+  eqe(
+    "+1234567890123456789012345678901235E-33",
+    Decimal128::from_str_lossy("1.23456789012345678901234567890123456"),
+  );
+}
+
+#[test]
+fn decimal128_parse_0008() {
+  let p = |b: &[u8]| Decimal128::parse_bytes(b).unwrap();
+  eqe("+12345E-2", p(b"123.45"));
+  eqe("-12345E-2", p(b"-123.45"));
+  eqe("+1200E+0", p(b"1200"));
+  eqe("+0E-3", p(b"0.000"));
+  eqe("+5E+0", p(b"5."));
+  eqe(
+    "+1234567890123456789012345678901234E-33",
+    p(b"1.234567890123456789012345678901234"),
+  );
+  eqe(
+    "+1234567890123456789012345678901234E+0",
+    p(b"1234567890123456789012345678901234"),
+  );
+  eqe(
+    "+1234567890123456789012345678901230E+1",
+    p(b"12345678901234567890123456789012300"),
+  );
+}
+
+#[test]
+fn decimal128_parse_0009() {
+  let kind = |b: &[u8]| Decimal128::parse_bytes(b).unwrap_err().kind();
+  assert_eq!(ParseErrorKind::InvalidDigit(2), kind(b"12\xff"));
+  assert_eq!(ParseErrorKind::InvalidDigit(1), kind(b"-"));
+  assert_eq!(ParseErrorKind::InvalidDigit(2), kind(b"1..2"));
+  assert_eq!(
+    ParseErrorKind::TooManyDigits,
+    kind(b"-12345678901234567890123456789012345")
+  );
+}
@@ -0,0 +1,144 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, DecimalError, Flags, RoundingMode};
+
+#[test]
+fn decimal128_power_0001() {
+  eqe("+225E-2", Decimal128::from("1.5").powi(2));
+  eqe("+22500E-4", Decimal128::from("1.50").powi(2));
+  eqe("+1024E+0", Decimal128::from(2).powi(10));
+  eqe("-8E+0", Decimal128::from(-2).powi(3));
+  eqe("+16E+0", Decimal128::from(-2).powi(4));
+  eqe("+1E+0", Decimal128::from(7).powi(0));
+  eqe("+7E+0", Decimal128::from(7).powi(1));
+  eqe("+1E+1000", Decimal128::from("1E+10").powi(100));
+}
+
+#[test]
+fn decimal128_power_0002() {
+  let (y, flags) = Decimal128::from(2).powi_with_flags(100);
+  eqe("+1267650600228229401496703205376E+0", y);
+  assert_eq!(Flags::empty(), flags);
+  let (y, flags) = Decimal128::from(2).powi_with_flags(120);
+  eqe("+1329227995784915872903807060280345E+3", y);
+  assert_eq!(Flags::INEXACT, flags);
+  eqe(
+    "+1329227995784915872903807060280344E+3",
+    Decimal128::from(2).powi_with(120, RoundingMode::Downward),
+  );
+  eqe(
+    "+1329227995784915872903807060280345E+3",
+    Decimal128::from(2).powi_with(120, RoundingMode::NearestAway),
+  );
+  eqe(
+    "+1329227995784915872903807060280345E+3",
+    Decimal128::from(2).powi_with(120, RoundingMode::Upward),
+  );
+}
+
+#[test]
+fn decimal128_power_0003() {
+  let x = Decimal128::from("1.000000000000000000000000000000001");
+  eqe("+1000000000000000000000000000000002E-33", x.powi(2));
+  eqe(
+    "+1000000000000000000000000000000003E-33",
+    x.powi_with(2, RoundingMode::Upward),
+  );
+  assert_eq!(Err(DecimalError::Inexact), x.checked_powi(2));
+  eqe("+1331E-3", Decimal128::from("1.1").checked_powi(3).unwrap());
+}
+
+#[test]
+fn decimal128_power_0004() {
+  eqe("+625E-4", Decimal128::from(2).powi(-4));
+  eqe("-125E-3", Decimal128::from(-2).powi(-3));
+  let (y, flags) = Decimal128::from(3).powi_with_flags(-1);
+  eqe("+3333333333333333333333333333333333E-34", y);
+  assert_eq!(Flags::INEXACT, flags);
+  eqe(
+    "+3333333333333333333333333333333334E-34",
+    Decimal128::from(3).powi_with(-1, RoundingMode::Upward),
+  );
+  let (y, flags) = Decimal128::zero().powi_with_flags(-1);
+  eqe("+Inf", y);
+  assert_eq!(Flags::DIVISION_BY_ZERO, flags);
+}
+
+#[test]
+fn decimal128_power_0005() {
+  let (y, flags) = Decimal128::from("1E+6000").powi_with_flags(2);
+  eqe("+Inf", y);
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, flags);
+  let (y, flags) = Decimal128::from("1.0000001").powi_with_flags(1000000);
+  assert!(y.to_string().starts_with("1.10517091254979341663838"));
+  assert_eq!(Flags::INEXACT, flags);
+  eqe("+Inf", Decimal128::from("inf").powi(3));
+  assert!(Decimal128::from("nan").powi(3).is_nan());
+}
+
+#[test]
+fn decimal128_power_0006() {
+  eqe(
+    "+2000000000000000000000000000000000E-33",
+    Decimal128::from(4).pow(Decimal128::from("0.5")),
+  );
+  eqe(
+    "+1414213562373095048801688724209698E-33",
+    Decimal128::from(2).pow(Decimal128::from("0.5")),
+  );
+  eqe(
+    "+1414213562373095048801688724209699E-33",
+    Decimal128::from(2).pow_with(Decimal128::from("0.5"), RoundingMode::Upward),
+  );
+  let (y, flags) = Decimal128::from(-8).pow_with_flags(Decimal128::from("0.5"));
+  assert!(y.is_nan());
+  assert_eq!(Flags::INVALID, flags);
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::from(-8).checked_pow(Decimal128::from("0.5"))
+  );
+}
+
+#[test]
+fn decimal128_power_0007() {
+  let ten = Decimal128::from(10);
+  let (y, flags) = ten.powi_with_flags(-7000);
+  eqe("+0E-6176", y);
+  assert_eq!(Flags::UNDERFLOW | Flags::INEXACT, flags);
+  let (y, flags) = ten.powi_with_flags(-6200);
+  eqe("+0E-6176", y);
+  assert_eq!(Flags::UNDERFLOW | Flags::INEXACT, flags);
+  let (y, flags) = ten.powi_with_flags(-6176);
+  eqe("+1E-6176", y);
+  assert_eq!(Flags::empty(), flags);
+  eqe("+1E-6176", ten.powi_with(-7000, RoundingMode::Upward));
+  assert_eq!(Err(DecimalError::Underflow), ten.checked_powi(-7000));
+  assert_eq!(Err(DecimalError::Underflow), ten.checked_powi(i32::MIN));
+  assert_eq!(Err(DecimalError::Overflow), ten.checked_powi(i32::MAX));
+  let (y, flags) = Decimal128::from(20).powi_with_flags(-7000);
+  eqe("+0E-6176", y);
+  assert_eq!(Flags::UNDERFLOW | Flags::INEXACT, flags);
+}
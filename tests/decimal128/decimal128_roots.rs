@@ -0,0 +1,158 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, DecimalError, Flags, RoundingMode};
+
+#[test]
+fn decimal128_roots_0001() {
+  eqe("+12E-1", Decimal128::from("1.44").sqrt());
+  eqe("+0E+0", Decimal128::zero().sqrt());
+  eqe(
+    "+1414213562373095048801688724209698E-33",
+    Decimal128::from(2).sqrt(),
+  );
+  eqe("+Inf", Decimal128::from("inf").sqrt());
+  assert!(Decimal128::from(-4).sqrt().is_nan());
+}
+
+#[test]
+fn decimal128_roots_0002() {
+  let x = Decimal128::from(2);
+  eqe(
+    "+1414213562373095048801688724209698E-33",
+    x.sqrt_with(RoundingMode::Downward),
+  );
+  eqe(
+    "+1414213562373095048801688724209699E-33",
+    x.sqrt_with(RoundingMode::Upward),
+  );
+  let (y, flags) = x.sqrt_with_flags();
+  eqe("+1414213562373095048801688724209698E-33", y);
+  assert_eq!(Flags::INEXACT, flags);
+  assert_eq!(Err(DecimalError::Inexact), x.checked_sqrt());
+  eqe("+3E+0", Decimal128::from(9).checked_sqrt().unwrap());
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    Decimal128::from(-1).checked_sqrt()
+  );
+}
+
+#[test]
+fn decimal128_roots_0003() {
+  eqe("+3E+0", Decimal128::from(27).cbrt());
+  eqe("-2E+0", Decimal128::from(-8).cbrt());
+  eqe(
+    "+1259921049894873164767210607278228E-33",
+    Decimal128::from(2).cbrt(),
+  );
+  eqe(
+    "+1259921049894873164767210607278229E-33",
+    Decimal128::from(2).cbrt_with(RoundingMode::Upward),
+  );
+  let (y, flags) = Decimal128::from(2).cbrt_with_flags();
+  eqe("+1259921049894873164767210607278228E-33", y);
+  assert_eq!(Flags::INEXACT, flags);
+}
+
+#[test]
+fn decimal128_roots_0004() {
+  eqe("+5E+0", Decimal128::from(3).hypot(Decimal128::from(-4)));
+  eqe(
+    "+5E+6000",
+    Decimal128::from("3E+6000").hypot(Decimal128::from("4E+6000")),
+  );
+  eqe(
+    "+1414213562373095048801688724209698E-33",
+    Decimal128::one().hypot(Decimal128::one()),
+  );
+  eqe(
+    "+1414213562373095048801688724209699E-33",
+    Decimal128::one().hypot_with(Decimal128::one(), RoundingMode::Upward),
+  );
+  let (y, flags) = Decimal128::from(3).hypot_with_flags(Decimal128::from(4));
+  eqe("+5E+0", y);
+  assert_eq!(Flags::empty(), flags);
+  assert_eq!(
+    Err(DecimalError::Inexact),
+    Decimal128::one().checked_hypot(Decimal128::one())
+  );
+  eqe(
+    "+Inf",
+    Decimal128::from("nan").hypot(Decimal128::from("-inf")),
+  );
+}
+
+#[test]
+fn decimal128_roots_0005() {
+  let big = Decimal128::from("1E+6144");
+  assert_eq!(Err(DecimalError::Inexact), big.checked_hypot(big));
+  assert_eq!(
+    Err(DecimalError::Inexact),
+    big.checked_hypot(Decimal128::from("1E-6176"))
+  );
+  eqe(
+    "+5E+6000",
+    Decimal128::from("3E+6000")
+      .checked_hypot(Decimal128::from("-4E+6000"))
+      .unwrap(),
+  );
+  eqe(
+    "+1000000000000000000000000000000000E+6111",
+    big.checked_hypot(Decimal128::zero()).unwrap(),
+  );
+  eqe(
+    "+200000000020000000001E+0",
+    Decimal128::from("20000000001")
+      .checked_hypot(Decimal128::from("200000000020000000000"))
+      .unwrap(),
+  );
+}
+
+#[test]
+fn decimal128_roots_0006() {
+  let big = Decimal128::from("1E+3000");
+  let one = Decimal128::one();
+  eqe(
+    "+1000000000000000000000000000000001E+2967",
+    big.hypot_with(one, RoundingMode::Upward),
+  );
+  eqe(
+    "+1000000000000000000000000000000001E+2967",
+    Decimal128::from(-1).hypot_with(-big, RoundingMode::Upward),
+  );
+  eqe("+1E+3000", big.hypot_with(one, RoundingMode::Downward));
+  eqe("+1E+3000", one.hypot_with(big, RoundingMode::TowardZero));
+  eqe(
+    "+100000000000000000E+2983",
+    big.hypot_with(Decimal128::zero(), RoundingMode::Upward),
+  );
+  let max = Decimal128::from("9999999999999999999999999999999999E+6111");
+  eqe("+Inf", max.hypot_with(one, RoundingMode::Upward));
+  eqe(
+    "+9999999999999999999999999999999999E+6111",
+    max.hypot_with(one, RoundingMode::Downward),
+  );
+  assert_eq!(Err(DecimalError::Inexact), big.checked_hypot(one));
+}
@@ -0,0 +1,152 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal128, RoundingMode};
+
+#[test]
+fn decimal128_rounding_0001() {
+  assert_eq!(RoundingMode::NearestEven, RoundingMode::default());
+}
+
+#[test]
+fn decimal128_rounding_0002() {
+  let x = Decimal128::from("9999999999999999999999999999999999");
+  let y = Decimal128::from("0.5");
+  eqe(
+    "+1000000000000000000000000000000000E+1",
+    x.add_with(y, RoundingMode::NearestEven),
+  );
+  eqe(
+    "+9999999999999999999999999999999999E+0",
+    x.add_with(y, RoundingMode::TowardZero),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0003() {
+  let x = Decimal128::from("-9999999999999999999999999999999999");
+  let y = Decimal128::from("0.5");
+  eqe(
+    "-1000000000000000000000000000000000E+1",
+    x.sub_with(y, RoundingMode::Downward),
+  );
+  eqe(
+    "-9999999999999999999999999999999999E+0",
+    x.sub_with(y, RoundingMode::Upward),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0004() {
+  let x = Decimal128::from("3333333333333333333333333333333333");
+  let y = Decimal128::from("0.5");
+  eqe(
+    "+1666666666666666666666666666666666E+0",
+    x.mul_with(y, RoundingMode::NearestEven),
+  );
+  eqe(
+    "+1666666666666666666666666666666667E+0",
+    x.mul_with(y, RoundingMode::NearestAway),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0005() {
+  let one = Decimal128::one();
+  let three = Decimal128::from(3_u8);
+  eqe(
+    "+3333333333333333333333333333333334E-34",
+    one.div_with(three, RoundingMode::Upward),
+  );
+  eqe(
+    "+3333333333333333333333333333333333E-34",
+    one.div_with(three, RoundingMode::Downward),
+  );
+  eqe(
+    "-3333333333333333333333333333333333E-34",
+    (-one).div_with(three, RoundingMode::TowardZero),
+  );
+  eqe(
+    "-3333333333333333333333333333333334E-34",
+    (-one).div_with(three, RoundingMode::Downward),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0006() {
+  let x = Decimal128::two();
+  eqe(
+    "+6931471805599453094172321214581766E-34",
+    x.ln_with(RoundingMode::NearestEven),
+  );
+  eqe(
+    "+6931471805599453094172321214581765E-34",
+    x.ln_with(RoundingMode::Downward),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0007() {
+  let x = Decimal128::one();
+  eqe(
+    "+2718281828459045235360287471352662E-33",
+    x.exp_with(RoundingMode::NearestEven),
+  );
+  eqe(
+    "+2718281828459045235360287471352662E-33",
+    x.exp_with(RoundingMode::TowardZero),
+  );
+  eqe(
+    "+2718281828459045235360287471352663E-33",
+    x.exp_with(RoundingMode::Upward),
+  );
+}
+
+#[test]
+fn decimal128_rounding_0008() {
+  let x = Decimal128::from("24.5");
+  eqe("+24E+0", x.round_dp_with(0, RoundingMode::NearestEven));
+  eqe("+25E+0", x.round_dp_with(0, RoundingMode::NearestAway));
+  eqe("+25E+0", x.round_dp_with(0, RoundingMode::Upward));
+  eqe("+24E+0", x.round_dp_with(0, RoundingMode::Downward));
+  eqe("+24E+0", x.round_dp_with(0, RoundingMode::TowardZero));
+}
+
+#[test]
+fn decimal128_rounding_0009() {
+  let x = Decimal128::from("-24.5");
+  eqe("-24E+0", x.round_dp_with(0, RoundingMode::NearestEven));
+  eqe("-25E+0", x.round_dp_with(0, RoundingMode::NearestAway));
+  eqe("-24E+0", x.round_dp_with(0, RoundingMode::Upward));
+  eqe("-25E+0", x.round_dp_with(0, RoundingMode::Downward));
+  eqe("-24E+0", x.round_dp_with(0, RoundingMode::TowardZero));
+}
+
+#[test]
+fn decimal128_rounding_0010() {
+  let x = Decimal128::from("1234.5678");
+  eqe("+12E+2", x.round_dp_with(-2, RoundingMode::TowardZero));
+  eqe("+13E+2", x.round_dp_with(-2, RoundingMode::Upward));
+}
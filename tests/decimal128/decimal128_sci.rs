@@ -0,0 +1,103 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::Decimal128;
+
+fn sci(n: i64, e: i32) -> String {
+  Decimal128::new(n, -e).to_sci_string()
+}
+
+fn eng(n: i64, e: i32) -> String {
+  Decimal128::new(n, -e).to_eng_string()
+}
+
+#[test]
+fn decimal128_sci_0001() {
+  assert_eq!("123", sci(123, 0));
+  assert_eq!("-123", sci(-123, 0));
+  assert_eq!("1.23E+3", sci(123, 1));
+  assert_eq!("1.23E+5", sci(123, 3));
+  assert_eq!("12.3", sci(123, -1));
+  assert_eq!("0.00123", sci(123, -5));
+  assert_eq!("1.23E-8", sci(123, -10));
+  assert_eq!("-1.23E-10", sci(-123, -12));
+  assert_eq!("0.000005", sci(5, -6));
+  assert_eq!("0.0000050", sci(50, -7));
+  assert_eq!("5E-7", sci(5, -7));
+}
+
+#[test]
+fn decimal128_sci_0002() {
+  assert_eq!("0", sci(0, 0));
+  assert_eq!("0.00", sci(0, -2));
+  assert_eq!("0E+2", sci(0, 2));
+  assert_eq!("-0", Decimal128::from("-0").to_sci_string());
+  assert_eq!("Infinity", Decimal128::from("inf").to_sci_string());
+  assert_eq!("-Infinity", Decimal128::from("-inf").to_sci_string());
+  assert_eq!("NaN", Decimal128::from("nan").to_sci_string());
+  assert_eq!("-sNaN", Decimal128::from("-snan").to_sci_string());
+  assert_eq!(
+    "NaN123",
+    "NaN123".parse::<Decimal128>().unwrap().to_sci_string()
+  );
+}
+
+#[test]
+fn decimal128_sci_0003() {
+  assert_eq!("1.23E+3", eng(123, 1));
+  assert_eq!("123E+3", eng(123, 3));
+  assert_eq!("12.3E-9", eng(123, -10));
+  assert_eq!("-123E-12", eng(-123, -12));
+  assert_eq!("700E-9", eng(7, -7));
+  assert_eq!("70", eng(7, 1));
+  assert_eq!("0.00E+3", eng(0, 1));
+  assert_eq!("0.0E+3", eng(0, 2));
+  assert_eq!("0E+3", eng(0, 3));
+  assert_eq!("0.00E-6", eng(0, -8));
+  assert_eq!("0.0000050", eng(50, -7));
+}
+
+#[test]
+fn decimal128_sci_0004() {
+  for s in [
+    "1.20E+7",
+    "-0.00",
+    "0E+2",
+    "1.234567890123456789012345678901234E+6144",
+    "1E-6176",
+    "12.00E-9",
+    "0.00E+3",
+    "-Infinity",
+    "sNaN42",
+  ] {
+    let x = s.parse::<Decimal128>().unwrap();
+    let y = x.to_sci_string().parse::<Decimal128>().unwrap();
+    let z = x.to_eng_string().parse::<Decimal128>().unwrap();
+    assert_eq!(format!("{:?}", x), format!("{:?}", y));
+    assert_eq!(format!("{:?}", x), format!("{:?}", z));
+    assert_eq!(x.to_sci_string(), y.to_sci_string());
+  }
+  eqe("+120E+5", "1.20E+7".parse().unwrap());
+}
@@ -0,0 +1,113 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{
+  get_context, local_context, set_context, with_context, Context, Decimal128,
+  Flags, RoundingMode,
+};
+
+#[test]
+fn decimal128_thread_context_0001() {
+  let ctx = get_context();
+  assert_eq!(RoundingMode::NearestEven, ctx.rounding());
+  assert!(ctx.traps().is_empty());
+}
+
+#[test]
+fn decimal128_thread_context_0002() {
+  let (x, ctx) = with_context(Context::new(RoundingMode::TowardZero), || {
+    Decimal128::from(2_u8) / Decimal128::from(3_u8)
+  });
+  eqe("+6666666666666666666666666666666666E-34", x);
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  eqe(
+    "+6666666666666666666666666666666667E-34",
+    Decimal128::from(2_u8) / Decimal128::from(3_u8),
+  );
+}
+
+#[test]
+fn decimal128_thread_context_0003() {
+  let (x, ctx) = with_context(Context::new(RoundingMode::NearestAway), || {
+    let mut x = Decimal128::from("24.5");
+    x += Decimal128::from("0.5");
+    x -= Decimal128::from("0.5");
+    x *= Decimal128::one();
+    x /= Decimal128::one();
+    x.round_dp(0)
+  });
+  eqe("+25E+0", x);
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  eqe("+24E+0", Decimal128::from("24.5").round_dp(0));
+}
+
+#[test]
+fn decimal128_thread_context_0004() {
+  let outer = local_context(Context::new(RoundingMode::Downward));
+  eqe(
+    "-6666666666666666666666666666666667E-34",
+    -Decimal128::from(2_u8) / Decimal128::from(3_u8),
+  );
+  {
+    let inner = local_context(Context::new(RoundingMode::Upward));
+    eqe(
+      "-6666666666666666666666666666666666E-34",
+      -Decimal128::from(2_u8) / Decimal128::from(3_u8),
+    );
+    assert_eq!(Flags::INEXACT, inner.flags());
+  }
+  assert_eq!(RoundingMode::Downward, outer.context().rounding());
+  assert_eq!(Flags::INEXACT, outer.flags());
+  drop(outer);
+  assert_eq!(RoundingMode::NearestEven, get_context().rounding());
+  assert!(get_context().flags().is_empty());
+}
+
+#[test]
+fn decimal128_thread_context_0005() {
+  set_context(Context::default().with_traps(Flags::INVALID));
+  let result = std::panic::catch_unwind(|| Decimal128::from(-1_i8).ln());
+  assert!(result.is_err());
+  assert_eq!(Flags::INVALID, get_context().flags());
+  set_context(Context::default());
+}
+
+#[test]
+fn decimal128_thread_context_0006() {
+  let (_, ctx) = with_context(Context::default(), || {
+    Decimal128::ten().exp();
+    Decimal128::one() / Decimal128::zero()
+  });
+  assert_eq!(Flags::DIVISION_BY_ZERO | Flags::INEXACT, ctx.flags());
+}
+
+#[test]
+fn decimal128_thread_context_0007() {
+  set_context(Context::new(RoundingMode::Upward));
+  let handle = std::thread::spawn(|| get_context().rounding());
+  assert_eq!(RoundingMode::NearestEven, handle.join().unwrap());
+  assert_eq!(RoundingMode::Upward, get_context().rounding());
+  set_context(Context::default());
+}
@@ -0,0 +1,178 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{
+  get_context, set_context, with_context, Context, Decimal128, Flags,
+  RoundingMode, Trap,
+};
+use std::cell::RefCell;
+
+thread_local! {
+  static TRAPS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+fn record(trap: &Trap) {
+  TRAPS.with(|traps| traps.borrow_mut().push(trap.to_string()));
+}
+
+fn recorded() -> Vec<String> {
+  TRAPS.with(|traps| traps.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn decimal128_traps_0001() {
+  let mut ctx = Context::default()
+    .with_traps(Flags::INEXACT | Flags::INVALID)
+    .with_trap_handler(record);
+  eqe("+5E-1", ctx.divide(Decimal128::one(), Decimal128::two()));
+  assert!(recorded().is_empty());
+  eqe(
+    "+3333333333333333333333333333333333E-34",
+    ctx.divide(Decimal128::one(), Decimal128::from(3_u8)),
+  );
+  ctx.ln(Decimal128::from(-2_i8));
+  ctx.quantize(Decimal128::from("1.25"), Decimal128::from("0.1"));
+  assert_eq!(
+    vec![
+      "decimal operation divide(1, 3) trapped: inexact".to_string(),
+      "decimal operation ln(-2) trapped: invalid".to_string(),
+      "decimal operation quantize(1.25, 0.1) trapped: inexact".to_string(),
+    ],
+    recorded()
+  );
+  assert_eq!(Flags::INEXACT | Flags::INVALID, ctx.flags());
+}
+
+#[test]
+fn decimal128_traps_0002() {
+  fn check(trap: &Trap) {
+    assert_eq!("multiply", trap.operation());
+    assert_eq!(2, trap.operands().len());
+    assert!(trap.result().is_infinite());
+    assert_eq!(Flags::OVERFLOW, trap.flags());
+    record(trap);
+  }
+  let ctx = Context::default()
+    .with_traps(Flags::OVERFLOW)
+    .with_trap_handler(check);
+  let (x, ctx) = with_context(ctx, || {
+    let x = Decimal128::from("1E+6000");
+    x * x
+  });
+  assert!(x.is_infinite());
+  assert_eq!(Flags::OVERFLOW | Flags::INEXACT, ctx.flags());
+  assert_eq!(1, recorded().len());
+}
+
+#[test]
+fn decimal128_traps_0003() {
+  let mut ctx = Context::default().with_trap_handler(record);
+  ctx.divide(Decimal128::one(), Decimal128::zero());
+  assert!(recorded().is_empty());
+  ctx.set_traps(Flags::DIVISION_BY_ZERO);
+  ctx.divide(Decimal128::one(), Decimal128::zero());
+  assert_eq!(1, recorded().len());
+  assert!(ctx.trap_handler().is_some());
+  ctx.set_trap_handler(None);
+  assert!(ctx.trap_handler().is_none());
+}
+
+#[test]
+#[should_panic(expected = "decimal operation add(sNaN, 1) trapped: invalid")]
+fn decimal128_traps_0004() {
+  let ctx = Context::default().with_traps(Flags::INVALID);
+  with_context(ctx, || Decimal128::from("sNaN") + Decimal128::one());
+}
+
+#[test]
+#[should_panic(expected = "decimal operation exp(1) trapped: inexact")]
+fn decimal128_traps_0005() {
+  set_context(Context::default().with_traps(Flags::INEXACT));
+  Decimal128::one().exp();
+}
+
+#[test]
+fn decimal128_traps_0006() {
+  fn nested(trap: &Trap) {
+    record(trap);
+    let x = Decimal128::one() / Decimal128::zero();
+    assert!(x.is_infinite());
+  }
+  let ctx = Context::default()
+    .with_traps(Flags::INEXACT)
+    .with_trap_handler(nested);
+  let (_, ctx) =
+    with_context(ctx, || Decimal128::one() / Decimal128::from(3_u8));
+  assert_eq!(Flags::INEXACT | Flags::DIVISION_BY_ZERO, ctx.flags());
+  assert_eq!(
+    vec!["decimal operation divide(1, 3) trapped: inexact".to_string()],
+    recorded()
+  );
+}
+
+#[test]
+fn decimal128_traps_0007() {
+  let ctx = Context::default()
+    .with_traps(Flags::INEXACT)
+    .with_trap_handler(record);
+  let (x, ctx) = with_context(ctx, || {
+    let x =
+      Decimal128::one().div_with(Decimal128::from(3_u8), RoundingMode::Upward);
+    assert_eq!(Flags::INEXACT, get_context().flags());
+    assert_eq!(RoundingMode::NearestEven, get_context().rounding());
+    x.round_dp_with(2, RoundingMode::TowardZero)
+  });
+  eqe("+33E-2", x);
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  assert_eq!(
+    vec![
+      "decimal operation divide(1, 3) trapped: inexact".to_string(),
+      "decimal operation quantize(0.3333333333333333333333333333333334, 0.01) trapped: inexact".to_string(),
+    ],
+    recorded()
+  );
+}
+
+#[test]
+fn decimal128_traps_0008() {
+  let ctx = Context::default()
+    .with_traps(Flags::INEXACT)
+    .with_trap_handler(record);
+  let (_, ctx) = with_context(ctx, || {
+    assert!(Decimal128::from("1.5")
+      .to_decimal32(RoundingMode::NearestEven)
+      .is_ok());
+    let x = Decimal128::from("1.23456789");
+    assert!(x.to_decimal32(RoundingMode::Downward).is_err());
+    assert!(x.to_decimal64(RoundingMode::Downward).is_ok());
+  });
+  assert_eq!(Flags::INEXACT, ctx.flags());
+  assert_eq!(
+    vec![
+      "decimal operation to_decimal32(1.23456789) trapped: inexact".to_string()
+    ],
+    recorded()
+  );
+}
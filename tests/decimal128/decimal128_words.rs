@@ -0,0 +1,211 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{
+  AmountWords, Capitalization, Decimal128, DecimalError, English,
+  FractionStyle, German, Language,
+};
+
+#[test]
+fn decimal128_words_0001() {
+  let en = |n| English.cardinal(n).unwrap();
+  assert_eq!("zero", en(0));
+  assert_eq!("seven", en(7));
+  assert_eq!("nineteen", en(19));
+  assert_eq!("forty", en(40));
+  assert_eq!("forty-two", en(42));
+  assert_eq!("one hundred", en(100));
+  assert_eq!("one hundred five", en(105));
+  assert_eq!("one thousand", en(1000));
+  assert_eq!("one thousand two hundred thirty-four", en(1234));
+  assert_eq!("one million one", en(1_000_001));
+  assert_eq!(
+    "twelve billion three hundred forty-five million six hundred seventy-eight thousand nine hundred one",
+    en(12_345_678_901)
+  );
+  assert!(English
+    .cardinal(10_u128.pow(36) - 1)
+    .unwrap()
+    .starts_with("nine hundred ninety-nine decillion"));
+  assert_eq!(None, English.cardinal(10_u128.pow(36)));
+}
+
+#[test]
+fn decimal128_words_0002() {
+  let de = |n| German.cardinal(n).unwrap();
+  assert_eq!("null", de(0));
+  assert_eq!("eins", de(1));
+  assert_eq!("sechzehn", de(16));
+  assert_eq!("einundzwanzig", de(21));
+  assert_eq!("dreißig", de(30));
+  assert_eq!("einhunderteins", de(101));
+  assert_eq!("einhundertzwölf", de(112));
+  assert_eq!("eintausend", de(1000));
+  assert_eq!("eintausendzweihundertvierunddreißig", de(1234));
+  assert_eq!("einundzwanzigtausendeins", de(21001));
+  assert_eq!("eine Million", de(1_000_000));
+  assert_eq!("zwei Millionen dreitausend", de(2_003_000));
+  assert_eq!("eine Milliarde eine Million eins", de(1_001_000_001));
+  assert_eq!("einhunderteine Millionen", de(101_000_000));
+  assert_eq!("einundzwanzig Millionen", de(21_000_000));
+  assert_eq!(
+    "zweihunderteine Milliarden einhunderteins",
+    de(201_000_000_101)
+  );
+  assert_eq!("ein", German.cardinal_before_unit(1).unwrap());
+  assert_eq!(None, German.cardinal(10_u128.pow(36)));
+}
+
+#[test]
+fn decimal128_words_0003() {
+  let words = AmountWords::new(English);
+  let spell = |x| words.spell(x).unwrap();
+  assert_eq!(
+    "one thousand two hundred thirty-four and 56/100",
+    spell(Decimal128::new(123456, 2))
+  );
+  assert_eq!("zero and 05/100", spell(Decimal128::new(5, 2)));
+  assert_eq!("one and 00/100", spell(Decimal128::one()));
+  assert_eq!("two and 00/100", spell(Decimal128::new(19999, 4)));
+  assert_eq!("minus seven and 50/100", spell(Decimal128::new(-75, 1)));
+  assert_eq!("zero and 00/100", spell(Decimal128::new(-1, 3)));
+  assert_eq!(
+    "one thousand and 000/1000",
+    AmountWords::new(English)
+      .with_minor_digits(3)
+      .spell(Decimal128::from(1000))
+      .unwrap()
+  );
+  assert_eq!(
+    "twelve",
+    AmountWords::new(English)
+      .with_minor_digits(0)
+      .spell(Decimal128::new(125, 1))
+      .unwrap()
+  );
+}
+
+#[test]
+fn decimal128_words_0004() {
+  let words = AmountWords::new(English)
+    .with_currency("dollar", "dollars")
+    .with_minor_unit("cent", "cents")
+    .with_fraction_style(FractionStyle::Words);
+  let spell = |x| words.spell(x).unwrap();
+  assert_eq!("one dollar and one cent", spell(Decimal128::new(101, 2)));
+  assert_eq!("two dollars", spell(Decimal128::from(2)));
+  assert_eq!("zero dollars and fifty cents", spell(Decimal128::new(5, 1)));
+  let words = words.with_fraction_style(FractionStyle::Numeric);
+  assert_eq!(
+    "one dollar and 01/100",
+    words.spell(Decimal128::new(101, 2)).unwrap()
+  );
+}
+
+#[test]
+fn decimal128_words_0005() {
+  let words = AmountWords::new(German)
+    .with_currency("Euro", "Euro")
+    .with_minor_unit("Cent", "Cent")
+    .with_fraction_style(FractionStyle::Words);
+  let spell = |x| words.spell(x).unwrap();
+  assert_eq!("ein Euro und ein Cent", spell(Decimal128::new(101, 2)));
+  assert_eq!(
+    "eintausendzweihundertvierunddreißig Euro und sechsundfünfzig Cent",
+    spell(Decimal128::new(123456, 2))
+  );
+  assert_eq!(
+    "minus zwei Millionen Euro",
+    spell(Decimal128::from(-2_000_000))
+  );
+}
+
+#[test]
+fn decimal128_words_0006() {
+  let x = Decimal128::new(123456, 2);
+  let words = AmountWords::new(English).with_currency("dollar", "dollars");
+  let spell = |c| words.clone().with_capitalization(c).spell(x).unwrap();
+  assert_eq!(
+    "One thousand two hundred thirty-four dollars and 56/100",
+    spell(Capitalization::Sentence)
+  );
+  assert_eq!(
+    "One Thousand Two Hundred Thirty-Four Dollars And 56/100",
+    spell(Capitalization::Title)
+  );
+  assert_eq!(
+    "Twenty-One Dollars And 00/100",
+    words
+      .clone()
+      .with_capitalization(Capitalization::Title)
+      .spell(Decimal128::from(21))
+      .unwrap()
+  );
+  assert_eq!(
+    "ONE THOUSAND TWO HUNDRED THIRTY-FOUR DOLLARS AND 56/100",
+    spell(Capitalization::Upper)
+  );
+  let words =
+    AmountWords::new(German).with_capitalization(Capitalization::Upper);
+  assert_eq!(
+    "DREISSIG UND 00/100",
+    words.spell(Decimal128::from(30)).unwrap()
+  );
+}
+
+#[test]
+fn decimal128_words_0007() {
+  let words = AmountWords::new(English);
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    words.spell(Decimal128::from("nan"))
+  );
+  assert_eq!(
+    Err(DecimalError::InvalidOperation),
+    words.spell(Decimal128::from("-inf"))
+  );
+  assert_eq!(
+    Err(DecimalError::Overflow),
+    words.spell(Decimal128::from("1E+36"))
+  );
+  assert_eq!(
+    Err(DecimalError::Overflow),
+    words.spell(Decimal128::from("1E+6000"))
+  );
+  assert!(words
+    .spell(Decimal128::from("999999999999999999999999999999999"))
+    .is_ok());
+  let numeric = |digits| {
+    AmountWords::new(English)
+      .with_minor_digits(digits)
+      .with_fraction_style(FractionStyle::Numeric)
+      .spell(Decimal128::one())
+  };
+  assert_eq!(
+    "one and 00000000000000000000000000000000000000/100000000000000000000000000000000000000",
+    numeric(38).unwrap()
+  );
+  assert_eq!(Err(DecimalError::Overflow), numeric(39));
+  assert_eq!(Err(DecimalError::Overflow), numeric(u32::MAX));
+}
@@ -24,9 +24,27 @@
 
 use dfp_number::Decimal128;
 
+mod decimal128_accounting;
+mod decimal128_checked;
+mod decimal128_cmp;
+mod decimal128_context;
+mod decimal128_convert;
 mod decimal128_display;
+mod decimal128_display_exp;
+mod decimal128_flags;
+mod decimal128_locale;
+mod decimal128_log;
+mod decimal128_mask;
 mod decimal128_new;
+mod decimal128_parse;
+mod decimal128_power;
+mod decimal128_roots;
 mod decimal128_round_dp;
+mod decimal128_rounding;
+mod decimal128_sci;
+mod decimal128_thread_context;
+mod decimal128_traps;
+mod decimal128_words;
 
 fn eqe(expected: &str, actual: Decimal128) {
   assert_eq!(expected, format!("{:?}", actual));
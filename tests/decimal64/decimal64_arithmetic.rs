@@ -0,0 +1,90 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::Decimal64;
+
+#[test]
+fn decimal64_arithmetic_0001() {
+  eqe(
+    "+1200E-2",
+    Decimal64::from("10.50") + Decimal64::from("1.5"),
+  );
+}
+
+#[test]
+fn decimal64_arithmetic_0002() {
+  eqe("+900E-2", Decimal64::from("10.50") - Decimal64::from("1.5"));
+}
+
+#[test]
+fn decimal64_arithmetic_0003() {
+  eqe(
+    "+15750E-3",
+    Decimal64::from("10.50") * Decimal64::from("1.5"),
+  );
+}
+
+#[test]
+fn decimal64_arithmetic_0004() {
+  eqe(
+    "+3333333333333333E-16",
+    Decimal64::one() / Decimal64::from(3_u8),
+  );
+}
+
+#[test]
+fn decimal64_arithmetic_0005() {
+  let mut x = Decimal64::from(5_u8);
+  x += Decimal64::one();
+  x *= Decimal64::two();
+  x -= Decimal64::two();
+  x /= Decimal64::ten();
+  eqe("+1E+0", x);
+}
+
+#[test]
+fn decimal64_arithmetic_0006() {
+  eqe("-5E+0", -Decimal64::from(5_i32));
+}
+
+#[test]
+fn decimal64_arithmetic_0007() {
+  assert!(Decimal64::from("1.0") == Decimal64::one());
+  assert!(Decimal64::from("0.5") < Decimal64::one());
+  assert!(Decimal64::ten() >= Decimal64::from("10.000"));
+  assert!(Decimal64::zero().is_zero());
+}
+
+#[test]
+fn decimal64_arithmetic_0008() {
+  eqe("+2E+0", Decimal64::one().max(Decimal64::two()));
+  eqe("+1E+0", Decimal64::one().min(Decimal64::two()));
+}
+
+#[test]
+fn decimal64_arithmetic_0009() {
+  eqe("+2302585092994046E-15", Decimal64::ten().ln());
+  eqe("+1E+0", Decimal64::zero().exp());
+}
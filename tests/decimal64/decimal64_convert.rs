@@ -0,0 +1,50 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::{Decimal32, Decimal64, Flags, RoundingMode};
+
+#[test]
+fn decimal64_convert_0001() {
+  eqe("+1234567E-3", Decimal64::from(Decimal32::new(1234567, 3)));
+}
+
+#[test]
+fn decimal64_convert_0002() {
+  let x = Decimal64::new(-25, 1);
+  let y = x.to_decimal32(RoundingMode::NearestEven).unwrap();
+  assert_eq!("-25E-1", format!("{:?}", y));
+}
+
+#[test]
+fn decimal64_convert_0003() {
+  let x = Decimal64::new(-12345675, 0);
+  let e = x.to_decimal32(RoundingMode::NearestEven).unwrap_err();
+  assert_eq!(Flags::INEXACT, e.flags());
+  assert_eq!("-1234568E+1", format!("{:?}", e.value()));
+  let e = x.to_decimal32(RoundingMode::Downward).unwrap_err();
+  assert_eq!("-1234568E+1", format!("{:?}", e.value()));
+  let e = x.to_decimal32(RoundingMode::Upward).unwrap_err();
+  assert_eq!("-1234567E+1", format!("{:?}", e.value()));
+}
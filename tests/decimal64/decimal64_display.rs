@@ -0,0 +1,88 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{with_context, Context, Decimal64, RoundingMode};
+
+#[test]
+fn decimal64_display_0001() {
+  assert_eq!("1.2", format!("{}", Decimal64::new(120, 2)));
+}
+
+#[test]
+fn decimal64_display_0002() {
+  assert_eq!("-1.2", format!("{}", Decimal64::new(-120, 2)));
+}
+
+#[test]
+fn decimal64_display_0003() {
+  assert_eq!("+120", format!("{:+}", Decimal64::new(120, 0)));
+}
+
+#[test]
+fn decimal64_display_0004() {
+  assert_eq!("0.0000012", format!("{}", Decimal64::new(120, 8)));
+}
+
+#[test]
+fn decimal64_display_0005() {
+  assert_eq!("  1.20", format!("{:6.2}", Decimal64::new(12, 1)));
+}
+
+#[test]
+fn decimal64_display_0006() {
+  assert_eq!("-000.00123", format!("{:010}", Decimal64::from("-0.00123")));
+}
+
+#[test]
+fn decimal64_display_0007() {
+  assert_eq!("+Inf", format!("{}", Decimal64::from("Inf")));
+}
+
+#[test]
+fn decimal64_display_0008() {
+  assert_eq!("+NaN", format!("{}", Decimal64::from("NaN")));
+}
+
+#[test]
+fn decimal64_display_0009() {
+  assert_eq!("0.13", format!("{:.2}", Decimal64::new(129, 3)));
+  assert_eq!("10.00", format!("{:.2}", Decimal64::new(9999, 3)));
+}
+
+#[test]
+fn decimal64_display_0010() {
+  assert_eq!("0", format!("{}", Decimal64::from("0E+5")));
+  assert_eq!("-0", format!("{}", Decimal64::from("-0.00")));
+  assert_eq!("0.000", format!("{:.3}", Decimal64::from("0E-7")));
+}
+
+#[test]
+fn decimal64_display_0011() {
+  let x = Decimal64::new(121, 3);
+  let (s, _) = with_context(Context::new(RoundingMode::Upward), || {
+    format!("{:.2} {:.2}", x, -x)
+  });
+  assert_eq!("0.13 -0.12", s);
+  assert_eq!("0.12", format!("{:.2}", x));
+}
@@ -0,0 +1,72 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use super::*;
+use dfp_number::Decimal64;
+
+#[test]
+fn decimal64_new_0001() {
+  eqe("+123456E-2", Decimal64::new(123456, 2));
+}
+
+#[test]
+fn decimal64_new_0002() {
+  eqe("+123456E+2", Decimal64::new(123456, -2));
+}
+
+#[test]
+fn decimal64_new_0003() {
+  eqe("+123456E-7", Decimal64::new(123456, 7));
+}
+
+#[test]
+fn decimal64_new_0004() {
+  eqe("+123000E+0", Decimal64::new(123000, 0));
+}
+
+#[test]
+fn decimal64_new_0005() {
+  eqe("+9223372036854776E+3", Decimal64::new(i64::MAX, 0));
+}
+
+#[test]
+fn decimal64_new_0006() {
+  eqe("+1000E+0", Decimal64::one_thousand());
+}
+
+#[test]
+fn decimal64_new_0007() {
+  eqe("-255E+0", Decimal64::from(-255_i16));
+}
+
+#[test]
+fn decimal64_new_0008() {
+  eqe("+1844674407370955E+4", Decimal64::from(u64::MAX));
+}
+
+#[test]
+fn decimal64_new_0009() {
+  eqe("+NaN", Decimal64::from("1\0"));
+  eqe("+NaN", "\0".parse::<Decimal64>().unwrap());
+}
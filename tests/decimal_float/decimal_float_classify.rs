@@ -0,0 +1,72 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{Decimal128, Decimal32, Decimal64, DecimalFloat};
+
+fn classify<T: DecimalFloat>(s: &str) -> (bool, bool, bool, bool, bool) {
+  let x = T::from(s);
+  (
+    x.is_zero(),
+    x.is_nan(),
+    x.is_infinite(),
+    x.is_finite(),
+    x.is_sign_negative(),
+  )
+}
+
+fn check(s: &str, expected: (bool, bool, bool, bool, bool)) {
+  assert_eq!(expected, classify::<Decimal32>(s));
+  assert_eq!(expected, classify::<Decimal64>(s));
+  assert_eq!(expected, classify::<Decimal128>(s));
+}
+
+#[test]
+fn decimal_float_classify_0001() {
+  check("0", (true, false, false, true, false));
+}
+
+#[test]
+fn decimal_float_classify_0002() {
+  check("-0.00", (true, false, false, true, true));
+}
+
+#[test]
+fn decimal_float_classify_0003() {
+  check("-1.5", (false, false, false, true, true));
+}
+
+#[test]
+fn decimal_float_classify_0004() {
+  check("NaN", (false, true, false, false, false));
+}
+
+#[test]
+fn decimal_float_classify_0005() {
+  check("-Inf", (false, false, true, false, true));
+}
+
+#[test]
+fn decimal_float_classify_0006() {
+  check("sNaN", (false, true, false, false, false));
+}
@@ -0,0 +1,52 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{Decimal128, Decimal32, Decimal64, DecimalFloat};
+
+#[test]
+fn decimal_float_consts_0001() {
+  assert_eq!(7, Decimal32::PRECISION);
+  assert_eq!(96, Decimal32::EMAX);
+  assert_eq!(-95, Decimal32::EMIN);
+  assert_eq!(4, Decimal32::BYTES);
+  assert_eq!(Decimal32::BYTES, std::mem::size_of::<Decimal32>());
+}
+
+#[test]
+fn decimal_float_consts_0002() {
+  assert_eq!(16, Decimal64::PRECISION);
+  assert_eq!(384, Decimal64::EMAX);
+  assert_eq!(-383, Decimal64::EMIN);
+  assert_eq!(8, Decimal64::BYTES);
+  assert_eq!(Decimal64::BYTES, std::mem::size_of::<Decimal64>());
+}
+
+#[test]
+fn decimal_float_consts_0003() {
+  assert_eq!(34, Decimal128::PRECISION);
+  assert_eq!(6144, Decimal128::EMAX);
+  assert_eq!(-6143, Decimal128::EMIN);
+  assert_eq!(16, Decimal128::BYTES);
+  assert_eq!(Decimal128::BYTES, std::mem::size_of::<Decimal128>());
+}
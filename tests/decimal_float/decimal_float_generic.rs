@@ -0,0 +1,78 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 senees
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use dfp_number::{Decimal128, Decimal32, Decimal64, DecimalFloat};
+
+/// Returns the future value of `principal` after `years` of yearly
+/// compounding at `rate` percent, rounded to cents.
+fn future_value<T: DecimalFloat>(principal: T, rate: T, years: u32) -> T {
+  let factor = T::one() + rate / T::from(100_u32);
+  let mut value = principal;
+  for _ in 0..years {
+    value *= factor;
+  }
+  value.round_dp(2)
+}
+
+/// Returns the largest number of significant digits `1/3` has.
+fn third_digits<T: DecimalFloat>() -> usize {
+  let third = T::one() / T::from(3_u32);
+  third.to_string().len() - 2
+}
+
+#[test]
+fn decimal_float_generic_0001() {
+  assert_eq!(
+    "1102.5",
+    future_value(Decimal32::from(1000_u32), Decimal32::new(5, 0), 2)
+      .to_string()
+  );
+  assert_eq!(
+    "1102.5",
+    future_value(Decimal64::from(1000_u32), Decimal64::new(5, 0), 2)
+      .to_string()
+  );
+  assert_eq!(
+    "1102.5",
+    future_value(Decimal128::from(1000_u32), Decimal128::new(5, 0), 2)
+      .to_string()
+  );
+}
+
+#[test]
+fn decimal_float_generic_0002() {
+  assert_eq!(Decimal32::PRECISION as usize, third_digits::<Decimal32>());
+  assert_eq!(Decimal64::PRECISION as usize, third_digits::<Decimal64>());
+  assert_eq!(Decimal128::PRECISION as usize, third_digits::<Decimal128>());
+}
+
+#[test]
+fn decimal_float_generic_0003() {
+  fn parse<T: DecimalFloat>(s: &str) -> T {
+    T::from(s)
+  }
+  assert_eq!("12.5", parse::<Decimal32>("12.50").to_string());
+  assert_eq!("12.5", parse::<Decimal64>("12.50").to_string());
+  assert_eq!("12.5", parse::<Decimal128>("12.50").to_string());
+}
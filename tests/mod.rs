@@ -22,4 +22,7 @@
  * SOFTWARE.
  */
 
-mod decimal128;
\ No newline at end of file
+mod decimal128;
+mod decimal32;
+mod decimal64;
+mod decimal_float;